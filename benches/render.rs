@@ -0,0 +1,69 @@
+//! Compares runtime-selected formatting through `IdFormat` against the
+//! compile-time selected `render`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+use steamid::render::{render, STEAMID2, STEAMID3, STEAMID64};
+use steamid::{IdFormat, SteamId};
+
+fn ids() -> Vec<SteamId> {
+    (0..1024u64)
+        .map(|n| SteamId::from(76561197960265728 + n * 7919))
+        .collect()
+}
+
+fn bench_formats(c: &mut Criterion) {
+    let ids = ids();
+    let mut group = c.benchmark_group("format");
+
+    group.bench_function("id_format/steamid64", |b| {
+        b.iter(|| {
+            for &id in &ids {
+                black_box(IdFormat::SteamId64(black_box(id)).to_string());
+            }
+        })
+    });
+    group.bench_function("render/steamid64", |b| {
+        b.iter(|| {
+            for id in &ids {
+                black_box(render::<STEAMID64>(black_box(id)));
+            }
+        })
+    });
+
+    group.bench_function("id_format/steamid2", |b| {
+        b.iter(|| {
+            for &id in &ids {
+                black_box(IdFormat::SteamId2(black_box(id)).to_string());
+            }
+        })
+    });
+    group.bench_function("render/steamid2", |b| {
+        b.iter(|| {
+            for id in &ids {
+                black_box(render::<STEAMID2>(black_box(id)));
+            }
+        })
+    });
+
+    group.bench_function("id_format/steamid3", |b| {
+        b.iter(|| {
+            for &id in &ids {
+                black_box(IdFormat::SteamId3(black_box(id)).to_string());
+            }
+        })
+    });
+    group.bench_function("render/steamid3", |b| {
+        b.iter(|| {
+            for id in &ids {
+                black_box(render::<STEAMID3>(black_box(id)));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_formats);
+criterion_main!(benches);
@@ -1,4 +1,7 @@
 //! Sample application that parses an input into a SteamId and manipulates it.
+//!
+//! Pass `--json` to print one JSON object per input instead, or `--explain`
+//! for a labeled breakdown of every format and field.
 
 extern crate steamid;
 
@@ -8,17 +11,31 @@ use steamid::{IdFormat, SteamId};
 
 fn main() {
     // Gather our CLI arguments
-    let args: Vec<String> = env::args().collect();
+    let args: Vec<String> = env::args().skip(1).collect();
+    let json = args.iter().any(|v| v == "--json");
+    let explain = args.iter().any(|v| v == "--explain");
+    let inputs: Vec<&String> = args
+        .iter()
+        .filter(|v| *v != "--json" && *v != "--explain")
+        .collect();
 
     // Dumb check, make sure they even tried providing a SteamID
-    (args.len() >= 2).then(|| ()).unwrap_or_else(|| {
+    if inputs.is_empty() {
         println!("No IDs provided!");
         std::process::exit(-1);
-    });
+    }
 
     // Process all of our passed strings
-    for input in args.iter().skip(1) {
-        match input.parse::<SteamId>() {
+    for input in inputs {
+        let parsed = input.parse::<SteamId>();
+        if json {
+            println!("{}", json_line(input, parsed));
+            continue;
+        }
+        match parsed {
+            Ok(v) if explain => {
+                println!("{}\n", v.explain());
+            }
             Ok(v) => {
                 println!("steamID64:\t{}", IdFormat::SteamId64(v));
                 println!("steamID:  \t{}", IdFormat::SteamId2(v));
@@ -30,5 +47,43 @@ fn main() {
             }
         }
     }
-    println!();
+    if !json && !explain {
+        println!();
+    }
+}
+
+/// Formats a single parse result as a line of JSON.
+fn json_line(input: &str, parsed: Result<SteamId, steamid::errors::ParseError>) -> String {
+    let input = json_string(input);
+    match parsed {
+        Ok(v) => format!(
+            "{{\"input\":{input},\"steamid64\":{},\"steamid\":{},\"steamid3\":{}}}",
+            json_string(&IdFormat::SteamId64(v).to_string()),
+            json_string(&IdFormat::SteamId2(v).to_string()),
+            json_string(&IdFormat::SteamId3(v).to_string()),
+        ),
+        Err(e) => format!(
+            "{{\"input\":{input},\"error\":{}}}",
+            json_string(&e.to_string())
+        ),
+    }
+}
+
+/// Quotes and escapes a string for JSON output.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
 }
@@ -1,8 +1,22 @@
-use std::fmt::Display;
+use core::fmt::Display;
+use core::str::FromStr;
 
+use crate::errors::{Field, ParseError};
 use crate::{mask, shift};
 use crate::{ChatType, SteamId};
 
+/// Returns every character that's a valid [AccountType] in a SteamId3.
+///
+/// Useful for validating the type field of user input before parsing.
+/// # Example
+/// ```
+/// assert!(steamid::account_type_chars().contains(&'U'));
+/// assert!(!steamid::account_type_chars().contains(&'X'));
+/// ```
+pub fn account_type_chars() -> &'static [char] {
+    &['I', 'U', 'M', 'G', 'A', 'P', 'C', 'g', 'L', 'T', 'c', 'a']
+}
+
 /// Defines the role this SteamId is used for.
 #[derive(Debug, PartialEq, Clone, Copy, Eq, PartialOrd, Ord, Hash)]
 pub enum AccountType {
@@ -23,18 +37,45 @@ pub enum AccountType {
     AnonUser,
 }
 
-impl Display for AccountType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", char::from(*self))
+impl AccountType {
+    /// Converts a raw value into an AccountType, failing on values with no
+    /// matching variant rather than returning [AccountType::Invalid] like
+    /// [From] does.
+    ///
+    /// **Note**: `TryFrom<u8>` can't be used for this, as the lossy `From<u8>`
+    /// already provides an infallible one.
+    /// # Example
+    /// ```
+    /// use steamid::AccountType;
+    ///
+    /// assert_eq!(AccountType::try_from_raw(7), Ok(AccountType::Clan));
+    /// assert!(AccountType::try_from_raw(15).is_err());
+    /// ```
+    pub fn try_from_raw(v: u8) -> Result<AccountType, ParseError> {
+        let account_type = AccountType::from(v);
+        (u8::from(account_type) == v)
+            .then_some(account_type)
+            .ok_or(ParseError::Invalid(Field::AccountType))
     }
-}
 
-impl From<AccountType> for char {
+    /// Returns the letter used for this account type in a SteamId3, the
+    /// **X** in `[X:Y:Z]`.
+    ///
+    /// Chat accounts use a different letter for each [ChatType], and types
+    /// without a letter of their own, such as [AccountType::ConsoleUser],
+    /// use `I`.
+    /// # Example
+    /// ```
+    /// use steamid::{AccountType, ChatType};
+    ///
+    /// assert_eq!(AccountType::Individual.steam3_letter(), 'U');
+    /// assert_eq!(AccountType::Chat(ChatType::Lobby).steam3_letter(), 'L');
+    /// ```
     #[rustfmt::skip]
-    fn from(acc: AccountType) -> Self {
+    pub fn steam3_letter(&self) -> char {
         use AccountType::*;
         use ChatType::*;
-        match acc {
+        match self {
             Invalid         => 'I',
             Individual      => 'U',
             Multiseat       => 'M',
@@ -53,6 +94,70 @@ impl From<AccountType> for char {
             AnonUser        => 'a',
         }
     }
+
+    /// Returns whether accounts of this type have a Steam Community page.
+    ///
+    /// Only individuals, clans and clan chats are linkable, servers and
+    /// other internal types will produce a [IdFormat::Url](crate::IdFormat::Url)
+    /// that doesn't resolve to anything.
+    ///
+    /// **Note**: Clan chats link to their group's page, which only
+    /// [SteamId::try_url](crate::SteamId::try_url) does.
+    /// # Example
+    /// ```
+    /// use steamid::AccountType;
+    ///
+    /// assert!(AccountType::Individual.has_community_url());
+    /// assert!(!AccountType::GameServer.has_community_url());
+    /// ```
+    pub fn has_community_url(&self) -> bool {
+        matches!(
+            self,
+            AccountType::Individual | AccountType::Clan | AccountType::Chat(ChatType::ClanChat)
+        )
+    }
+
+    /// Returns a human readable name for this account type, e.g. `Game
+    /// Server`, where [Display] only writes the SteamId3 letter.
+    /// # Example
+    /// ```
+    /// use steamid::{AccountType, ChatType};
+    ///
+    /// assert_eq!(AccountType::Individual.name(), "Individual");
+    /// assert_eq!(AccountType::Chat(ChatType::ClanChat).name(), "Clan Chat");
+    /// ```
+    #[rustfmt::skip]
+    pub fn name(&self) -> &'static str {
+        use AccountType::*;
+        match self {
+            Invalid                             => "Invalid",
+            Individual                          => "Individual",
+            Multiseat                           => "Multiseat",
+            GameServer                          => "Game Server",
+            AnonGameServer                      => "Anonymous Game Server",
+            Pending                             => "Pending",
+            ContentServer                       => "Content Server",
+            Clan                                => "Clan",
+            Chat(ChatType::None)                => "Chat",
+            Chat(ChatType::MatchMakingLobby)    => "Matchmaking Lobby",
+            Chat(ChatType::Lobby)               => "Lobby",
+            Chat(ChatType::ClanChat)            => "Clan Chat",
+            ConsoleUser                         => "Console User",
+            AnonUser                            => "Anonymous User",
+        }
+    }
+}
+
+impl Display for AccountType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
+impl From<AccountType> for char {
+    fn from(acc: AccountType) -> Self {
+        acc.steam3_letter()
+    }
 }
 
 // We can safely use a u8 here since AccountType is only 4-bits in packed repr.
@@ -121,9 +226,38 @@ impl From<char> for AccountType {
     }
 }
 
-impl From<SteamId> for AccountType {
+/// Parses a single SteamId3 type letter, such as `U` or `g`.
+///
+/// Unlike `From<char>`, letters that aren't in [account_type_chars] are
+/// rejected rather than becoming [AccountType::Invalid].
+/// # Example
+/// ```
+/// use steamid::AccountType;
+///
+/// assert_eq!("U".parse(), Ok(AccountType::Individual));
+/// assert!("gg".parse::<AccountType>().is_err());
+/// ```
+impl FromStr for AccountType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let c = chars.next().ok_or(ParseError::Empty)?;
+        chars
+            .next()
+            .is_none()
+            .then_some(())
+            .ok_or(ParseError::UnknownFormat)?;
+        account_type_chars()
+            .contains(&c)
+            .then(|| AccountType::from(c))
+            .ok_or(ParseError::Invalid(Field::AccountType))
+    }
+}
+
+impl From<&SteamId> for AccountType {
     #[rustfmt::skip]
-    fn from(steamid: SteamId) -> Self {
+    fn from(steamid: &SteamId) -> Self {
         use AccountType::*;
 
         let account_type = (steamid.id & mask::ACCOUNT_TYPE) >> shift::ACCOUNT_TYPE ;
@@ -135,6 +269,12 @@ impl From<SteamId> for AccountType {
     }
 }
 
+impl From<SteamId> for AccountType {
+    fn from(steamid: SteamId) -> Self {
+        AccountType::from(&steamid)
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////
 // Unit Testing
 /////////////////////////////////////////////////////////////////////////////
@@ -168,15 +308,15 @@ mod tests {
             .account_number(1)
             .authentication_server(1);
         assert_eq!(
-            AccountType::from(bld.clone().account_type('L').finish()),
+            AccountType::from(bld.account_type('L').finish()),
             AccountType::Chat(ChatType::Lobby)
         );
         assert_eq!(
-            AccountType::from(bld.clone().account_type('T').finish()),
+            AccountType::from(bld.account_type('T').finish()),
             AccountType::Chat(ChatType::MatchMakingLobby)
         );
         assert_eq!(
-            AccountType::from(bld.clone().account_type('c').finish()),
+            AccountType::from(bld.account_type('c').finish()),
             AccountType::Chat(ChatType::ClanChat)
         );
     }
@@ -237,6 +377,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn checked_conversion() {
+        for v in 0..=10 {
+            assert_eq!(AccountType::try_from_raw(v), Ok(AccountType::from(v)));
+        }
+        assert_eq!(
+            AccountType::try_from_raw(11),
+            Err(errors::ParseError::Invalid(errors::Field::AccountType))
+        );
+        assert!(AccountType::try_from_raw(255).is_err());
+    }
+
+    #[test]
+    fn steam3_letters() {
+        use AccountType::*;
+        assert_eq!(Invalid.steam3_letter(), 'I');
+        assert_eq!(Individual.steam3_letter(), 'U');
+        assert_eq!(Multiseat.steam3_letter(), 'M');
+        assert_eq!(GameServer.steam3_letter(), 'G');
+        assert_eq!(AnonGameServer.steam3_letter(), 'A');
+        assert_eq!(Pending.steam3_letter(), 'P');
+        assert_eq!(ContentServer.steam3_letter(), 'C');
+        assert_eq!(Clan.steam3_letter(), 'g');
+        assert_eq!(Chat(ChatType::MatchMakingLobby).steam3_letter(), 'T');
+        assert_eq!(Chat(ChatType::Lobby).steam3_letter(), 'L');
+        assert_eq!(Chat(ChatType::ClanChat).steam3_letter(), 'c');
+        assert_eq!(Chat(ChatType::None).steam3_letter(), 'c');
+        assert_eq!(ConsoleUser.steam3_letter(), 'I');
+        assert_eq!(AnonUser.steam3_letter(), 'a');
+    }
+
+    #[test]
+    fn valid_chars() {
+        for &c in account_type_chars() {
+            let account_type = AccountType::from(c);
+            assert_eq!(char::from(account_type), c);
+            if c != 'I' {
+                assert_ne!(account_type, AccountType::Invalid);
+            }
+        }
+    }
+
+    #[test]
+    fn community_url() {
+        use AccountType::*;
+        assert!(Individual.has_community_url());
+        assert!(Clan.has_community_url());
+        assert!(Chat(ChatType::ClanChat).has_community_url());
+
+        assert!(!Invalid.has_community_url());
+        assert!(!Multiseat.has_community_url());
+        assert!(!GameServer.has_community_url());
+        assert!(!AnonGameServer.has_community_url());
+        assert!(!Pending.has_community_url());
+        assert!(!ContentServer.has_community_url());
+        assert!(!Chat(ChatType::Lobby).has_community_url());
+        assert!(!Chat(ChatType::MatchMakingLobby).has_community_url());
+        assert!(!ConsoleUser.has_community_url());
+        assert!(!AnonUser.has_community_url());
+    }
+
     #[test]
     fn account_type_fmt() {
         for v in 0..=10 {
@@ -244,4 +445,36 @@ mod tests {
             assert_eq!(format!("{f}"), char::from(f).to_string());
         }
     }
+
+    #[test]
+    fn from_str() {
+        use errors::{Field, ParseError};
+        use AccountType::*;
+
+        for (s, account_type) in [
+            ("I", Invalid),
+            ("U", Individual),
+            ("M", Multiseat),
+            ("G", GameServer),
+            ("A", AnonGameServer),
+            ("P", Pending),
+            ("C", ContentServer),
+            ("g", Clan),
+            ("L", Chat(ChatType::Lobby)),
+            ("T", Chat(ChatType::MatchMakingLobby)),
+            ("c", Chat(ChatType::ClanChat)),
+            ("a", AnonUser),
+        ] {
+            assert_eq!(s.parse(), Ok(account_type), "{s}");
+        }
+        assert_eq!("".parse::<AccountType>(), Err(ParseError::Empty));
+        assert_eq!("gg".parse::<AccountType>(), Err(ParseError::UnknownFormat));
+        for s in ["1", ":", " ", "Z", "u"] {
+            assert_eq!(
+                s.parse::<AccountType>(),
+                Err(ParseError::Invalid(Field::AccountType)),
+                "{s}"
+            );
+        }
+    }
 }
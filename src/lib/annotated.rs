@@ -0,0 +1,61 @@
+use crate::SteamId;
+
+/// A [SteamId] along with whether the account is limited.
+///
+/// Limited accounts aren't encoded in the SteamId itself, this only carries
+/// the flag from wherever it was looked up, such as the Steam Web API.
+///
+/// With the `serde` feature this serializes as `{"id": ..., "limited": ...}`,
+/// with the id as a SteamId64.
+/// # Example
+/// ```
+/// use steamid::SteamId;
+///
+/// let user = SteamId::from(76561197990953833).with_limited_flag(true);
+/// assert!(user.limited);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnnotatedSteamId {
+    pub id: SteamId,
+    pub limited: bool,
+}
+
+impl SteamId {
+    /// Wraps this SteamId in an [AnnotatedSteamId] with the given limited
+    /// account status.
+    pub fn with_limited_flag(self, limited: bool) -> AnnotatedSteamId {
+        AnnotatedSteamId { id: self, limited }
+    }
+}
+
+impl From<AnnotatedSteamId> for SteamId {
+    fn from(annotated: AnnotatedSteamId) -> Self {
+        annotated.id
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Unit Testing
+/////////////////////////////////////////////////////////////////////////////
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn serde_round_trip() {
+        let user = SteamId::from(76561197990953833).with_limited_flag(true);
+        let json = serde_json::to_string(&user).unwrap();
+        assert_eq!(json, r#"{"id":76561197990953833,"limited":true}"#);
+        assert_eq!(
+            serde_json::from_str::<AnnotatedSteamId>(&json).unwrap(),
+            user
+        );
+
+        let json = r#"{"id":"[U:1:30688105]","limited":false}"#;
+        assert_eq!(
+            serde_json::from_str::<AnnotatedSteamId>(json).unwrap(),
+            SteamId::from(76561197990953833).with_limited_flag(false)
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! [Arbitrary](::arbitrary::Arbitrary) support for fuzzing, behind the
+//! `arbitrary` feature.
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{AccountType, ChatType, Instance, SteamId, Universe};
+
+/// Generated from an arbitrary [u64], so any bit pattern can come up,
+/// including ids with unknown or invalid fields.
+impl<'a> Arbitrary<'a> for SteamId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(SteamId::from(u64::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u64::size_hint(depth)
+    }
+}
+
+impl<'a> Arbitrary<'a> for ChatType {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        use ChatType::*;
+        u.choose(&[None, MatchMakingLobby, Lobby, ClanChat])
+            .copied()
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+/// Only generates the named places, never [Instance::Raw].
+impl<'a> Arbitrary<'a> for Instance {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let places = [
+            Instance::None,
+            Instance::Desktop,
+            Instance::Console,
+            Instance::Web,
+        ];
+        let place = u.choose(&places)?;
+        Ok(place(ChatType::arbitrary(u)?))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        ::arbitrary::size_hint::and(u32::size_hint(depth), ChatType::size_hint(depth))
+    }
+}
+
+impl<'a> Arbitrary<'a> for AccountType {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // Every raw account type, with the chat type picked separately.
+        match AccountType::from(u.int_in_range(0..=10u8)?) {
+            AccountType::Chat(_) => Ok(AccountType::Chat(ChatType::arbitrary(u)?)),
+            account_type => Ok(account_type),
+        }
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        ::arbitrary::size_hint::and(u8::size_hint(depth), (0, ChatType::size_hint(depth).1))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Universe {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        use Universe::*;
+        u.choose(&[Unspecified, Public, Beta, Internal, Dev, RC])
+            .copied()
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+/// A [SteamId] whose [Arbitrary] implementation only generates valid
+/// individual accounts.
+///
+/// Every generated SteamId is a [Universe::Public](crate::Universe::Public),
+/// [AccountType::Individual](crate::AccountType::Individual),
+/// [Instance::Desktop](crate::Instance::Desktop) id with a non-zero account
+/// id, for fuzzing code that only handles users.
+/// # Example
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use steamid::arbitrary::ArbitraryIndividual;
+/// use steamid::AccountType;
+///
+/// let mut u = Unstructured::new(&[0x69, 0x43, 0xD4, 0x01]);
+/// let user = ArbitraryIndividual::arbitrary(&mut u).unwrap().0;
+/// assert_eq!(user.account_type(), AccountType::Individual);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArbitraryIndividual(pub SteamId);
+
+impl<'a> Arbitrary<'a> for ArbitraryIndividual {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let account_id = u.int_in_range(1..=u32::MAX)?;
+        Ok(ArbitraryIndividual(SteamId::from_account_id(account_id)))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        u32::size_hint(depth)
+    }
+}
+
+impl From<ArbitraryIndividual> for SteamId {
+    fn from(individual: ArbitraryIndividual) -> Self {
+        individual.0
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Unit Testing
+/////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    use super::ArbitraryIndividual;
+    use crate::*;
+
+    #[test]
+    fn individual_only() {
+        let data: Vec<u8> = (0..=255).cycle().step_by(7).take(4096).collect();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let user = SteamId::from(ArbitraryIndividual::arbitrary(&mut u).unwrap());
+            assert_eq!(user.universe(), Universe::Public);
+            assert_eq!(user.account_type(), AccountType::Individual);
+            assert_eq!(user.instance(), Instance::Desktop(ChatType::None));
+            assert!(SteamIdBuilder::from(user).validate().is_empty());
+        }
+        // Running out of data still gives a valid id.
+        let empty = ArbitraryIndividual::arbitrary(&mut Unstructured::new(&[])).unwrap();
+        assert_ne!(empty.0.account_id(), 0);
+    }
+
+    #[test]
+    fn from_bytes() {
+        let data = [0x69, 0x43, 0xD4, 0x01, 0x01, 0x00, 0x10, 0x01];
+        let id = SteamId::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert_eq!(u64::from(id), 76561197990953833);
+
+        let data: Vec<u8> = (0..=255).cycle().step_by(11).take(4096).collect();
+        let mut u = Unstructured::new(&data);
+        while !u.is_empty() {
+            let account_type = AccountType::arbitrary(&mut u).unwrap();
+            assert!(AccountType::try_from_raw(u8::from(account_type)).is_ok());
+            let universe = Universe::arbitrary(&mut u).unwrap();
+            assert_eq!(Universe::try_from_raw(u8::from(universe)), Ok(universe));
+            let instance = Instance::arbitrary(&mut u).unwrap();
+            assert_eq!(Instance::try_from_raw(u32::from(instance)), Ok(instance));
+            let chat_type = ChatType::arbitrary(&mut u).unwrap();
+            assert_eq!(ChatType::from(u8::from(chat_type)), chat_type);
+        }
+    }
+}
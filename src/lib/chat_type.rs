@@ -4,9 +4,10 @@ use crate::{mask, shift, SteamId};
 use crate::account_type::AccountType;
 
 /// Defines the type of Chat a [AccountType::Chat] can be.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ChatType {
     /// Default for all non-Chat account types
+    #[default]
     None,
     MatchMakingLobby,
     Lobby,
@@ -14,12 +15,6 @@ pub enum ChatType {
     ClanChat,
 }
 
-impl Default for ChatType {
-    fn default() -> Self {
-        ChatType::None
-    }
-}
-
 impl From<ChatType> for u8 {
     #[rustfmt::skip]
     fn from(chat: ChatType) -> Self {
@@ -45,13 +40,19 @@ impl From<u8> for ChatType {
         }
     }
 }
-impl From<SteamId> for ChatType {
-    fn from(steamid: SteamId) -> Self {
+impl From<&SteamId> for ChatType {
+    fn from(steamid: &SteamId) -> Self {
         // CHAT_TYPE is an 8-bit mask, so we're safe to cast into a u8 here.
         ChatType::from(((steamid.id & mask::CHAT_TYPE) >> shift::CHAT_TYPE) as u8)
     }
 }
 
+impl From<SteamId> for ChatType {
+    fn from(steamid: SteamId) -> Self {
+        ChatType::from(&steamid)
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////
 // Unit Testing
 /////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,113 @@
+use crate::{mask, shift, SteamId};
+
+/// The raw values packed into a [SteamId], as plain named fields.
+///
+/// Unlike the field enums, these are the exact bits stored in the SteamId,
+/// so converting to and from a [SteamId] is lossless.
+///
+/// With the `serde` feature this can be embedded in a larger struct with
+/// `#[serde(flatten)]`.
+/// # Example
+/// ```
+/// use steamid::{SteamId, SteamIdComponents};
+///
+/// let user: SteamId = "[U:1:30688105]".parse().unwrap();
+/// let parts = SteamIdComponents::from(user);
+/// assert_eq!(parts.account_number, 15344052);
+/// assert_eq!(SteamId::from(parts), user);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SteamIdComponents {
+    pub universe: u8,
+    pub account_type: u8,
+    pub instance: u32,
+    pub account_number: u32,
+    pub authentication_server: u8,
+}
+
+impl From<SteamId> for SteamIdComponents {
+    fn from(steamid: SteamId) -> Self {
+        // Each mask is narrow enough for the type we're casting into.
+        SteamIdComponents {
+            universe: ((steamid.id & mask::UNIVERSE) >> shift::UNIVERSE) as u8,
+            account_type: ((steamid.id & mask::ACCOUNT_TYPE) >> shift::ACCOUNT_TYPE) as u8,
+            instance: ((steamid.id & mask::INSTANCE) >> shift::INSTANCE) as u32,
+            account_number: ((steamid.id & mask::ACCOUNT_NUMBER) >> shift::ACCOUNT_NUMBER) as u32,
+            authentication_server: ((steamid.id & mask::AUTH_SERVER) >> shift::AUTH_SERVER) as u8,
+        }
+    }
+}
+
+impl From<SteamIdComponents> for SteamId {
+    fn from(parts: SteamIdComponents) -> Self {
+        // Out of range values are truncated to fit their field.
+        let id = ((parts.universe as u64) << shift::UNIVERSE) & mask::UNIVERSE
+            | ((parts.account_type as u64) << shift::ACCOUNT_TYPE) & mask::ACCOUNT_TYPE
+            | ((parts.instance as u64) << shift::INSTANCE) & mask::INSTANCE
+            | ((parts.account_number as u64) << shift::ACCOUNT_NUMBER) & mask::ACCOUNT_NUMBER
+            | ((parts.authentication_server as u64) << shift::AUTH_SERVER) & mask::AUTH_SERVER;
+        SteamId::from(id)
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Unit Testing
+/////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn reciprocity() {
+        for raw in [76561197990953833, 103582791464489035, 0, u64::MAX] {
+            let id = SteamId::from(raw);
+            assert_eq!(SteamId::from(SteamIdComponents::from(id)), id);
+        }
+    }
+
+    #[test]
+    fn values() {
+        let parts = SteamIdComponents::from(SteamId::from(103582791464489035));
+        assert_eq!(
+            parts,
+            SteamIdComponents {
+                universe: 1,
+                account_type: 7,
+                instance: 0,
+                account_number: 17483813,
+                authentication_server: 1,
+            }
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_flatten() {
+        #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+        struct Player {
+            name: String,
+            #[serde(flatten)]
+            steamid: SteamIdComponents,
+        }
+
+        let player = Player {
+            name: "Glitchvid".to_string(),
+            steamid: SteamIdComponents::from(SteamId::from(76561197990953833)),
+        };
+        let json = serde_json::to_value(&player).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Glitchvid",
+                "universe": 1,
+                "account_type": 1,
+                "instance": 1,
+                "account_number": 15344052,
+                "authentication_server": 1,
+            })
+        );
+        let back: Player = serde_json::from_value(json).unwrap();
+        assert_eq!(back, player);
+    }
+}
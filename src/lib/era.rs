@@ -0,0 +1,61 @@
+use crate::SteamId;
+
+// Account ids are handed out sequentially, so these roughly mark the end of
+// each era. They're only approximate and should never be relied on.
+const EARLY_END: u32 = 50_000_000;
+const MID_END: u32 = 400_000_000;
+
+/// A rough guess of when an account was registered, see [SteamId::approx_era].
+///
+/// **Note**: This is purely a heuristic based on the account id, Steam does
+/// not encode any registration date into a SteamId.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Era {
+    /// Account ids below `50,000,000`, roughly registered before 2010.
+    Early,
+    /// Account ids below `400,000,000`, roughly registered before 2017.
+    Mid,
+    /// Everything newer.
+    Recent,
+}
+
+impl From<SteamId> for Era {
+    fn from(steamid: SteamId) -> Self {
+        match steamid.steam3_z() {
+            v if v < EARLY_END => Era::Early,
+            v if v < MID_END => Era::Mid,
+            _ => Era::Recent,
+        }
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Unit Testing
+/////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn known_ids() {
+        assert_eq!(SteamId::from(76561197990953833).approx_era(), Era::Early);
+        assert_eq!(SteamId::from(76561198024962300).approx_era(), Era::Mid);
+        assert_eq!(SteamId::from(76561198930384249).approx_era(), Era::Recent);
+    }
+
+    #[test]
+    fn thresholds() {
+        let user = |account_id: u64| {
+            SteamIdBuilder::new()
+                .authentication_server(account_id & 1)
+                .account_number(account_id >> 1)
+                .finish()
+        };
+        assert_eq!(Era::from(user(0)), Era::Early);
+        assert_eq!(Era::from(user(49_999_999)), Era::Early);
+        assert_eq!(Era::from(user(50_000_000)), Era::Mid);
+        assert_eq!(Era::from(user(399_999_999)), Era::Mid);
+        assert_eq!(Era::from(user(400_000_000)), Era::Recent);
+        assert_eq!(Era::from(user(u32::MAX as u64)), Era::Recent);
+    }
+}
@@ -1,5 +1,5 @@
 //! Module to disambiguate our error-related types.
-use std::fmt::{self, Debug, Display};
+use core::fmt::{self, Debug, Display};
 
 /// Parsing components of a SteamId
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
@@ -62,6 +62,26 @@ pub enum ParseError {
     Other(&'static str),
 }
 
+impl ParseError {
+    /// Returns whether the input may still become valid with more text, as
+    /// with [ParseError::TooShort] and [ParseError::Empty].
+    ///
+    /// Useful for live validation, telling "keep typing" apart from input
+    /// that is already wrong.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let partial = "STEAM_1:1".parse::<SteamId>().unwrap_err();
+    /// assert!(partial.is_incomplete());
+    /// let wrong = "STEAM_1:1:z".parse::<SteamId>().unwrap_err();
+    /// assert!(!wrong.is_incomplete());
+    /// ```
+    pub fn is_incomplete(&self) -> bool {
+        matches!(self, ParseError::TooShort | ParseError::Empty)
+    }
+}
+
 impl Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -74,8 +94,34 @@ impl Display for ParseError {
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for ParseError {}
 
+/// Reasons why building a SteamId with
+/// [SteamIdBuilder::try_finish](crate::SteamIdBuilder::try_finish) might fail.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
+pub enum BuildError {
+    /// A value given to the builder didn't fit in its field, and was
+    /// truncated.
+    OutOfRange(Field),
+
+    /// A field holds a value that isn't valid for a SteamId, along with the
+    /// reason, see [SteamIdBuilder::validate](crate::SteamIdBuilder::validate).
+    Invalid(Field, &'static str),
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::OutOfRange(v) => write!(f, "value out of range for {v}"),
+            BuildError::Invalid(v, reason) => write!(f, "invalid {v}: {reason}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
 /////////////////////////////////////////////////////////////////////////////
 // Unit Testing
 /////////////////////////////////////////////////////////////////////////////
@@ -104,4 +150,15 @@ mod tests {
         // Other is currently unused.
         errors::ParseError::Other("unused").to_string();
     }
+
+    #[test]
+    fn error_incomplete() {
+        use errors::{Field, ParseError};
+
+        assert!(ParseError::TooShort.is_incomplete());
+        assert!(ParseError::Empty.is_incomplete());
+        assert!(!ParseError::UnknownFormat.is_incomplete());
+        assert!(!ParseError::Invalid(Field::AccountNumber).is_incomplete());
+        assert!(!ParseError::Other("unused").is_incomplete());
+    }
 }
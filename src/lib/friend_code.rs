@@ -0,0 +1,81 @@
+// CS:GO / CS2 "friend codes", e.g. `SUCVS-FADA`.
+//
+// The account id is split into nibbles, each followed by one bit of an MD5
+// hash of the account id, then byte-swapped and written as base32. The code
+// only covers the account id, every other field is left to the caller.
+use core::fmt;
+
+use crate::errors::{Field, ParseError};
+
+// Base32 without `I` and `O`.
+const ALPHABET: &[u8; 32] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+// "CSGO" as a big-endian u32, placed above the account id before hashing.
+const SALT: u64 = 0x4353474F << 32;
+// Length of the code, including the dash, e.g. `SUCVS-FADA`.
+const CODE_LEN: usize = 10;
+const DASH: usize = 5;
+
+fn hash(account_id: u32) -> u32 {
+    let digest = md5::compute((SALT | account_id as u64).to_le_bytes());
+    u32::from_le_bytes([digest[0], digest[1], digest[2], digest[3]])
+}
+
+fn pack(account_id: u32) -> u64 {
+    let hash = hash(account_id);
+    let mut id = account_id;
+    let mut packed = 0u64;
+    for i in 0..8 {
+        let a = (packed << 4) as u32 | (id & 0xF);
+        id >>= 4;
+        packed = ((packed >> 28) << 32) | a as u64;
+        packed = ((packed >> 31) << 32) | ((a << 1) | ((hash >> i) & 1)) as u64;
+    }
+    packed.swap_bytes()
+}
+
+pub(crate) fn write_friend_code<W: fmt::Write>(w: &mut W, account_id: u32) -> fmt::Result {
+    // The first 4 characters are always `AAAA-`, which Valve leaves out.
+    let mut packed = pack(account_id) >> 20;
+    for i in 0..9 {
+        if i == DASH {
+            w.write_char('-')?;
+        }
+        w.write_char(ALPHABET[(packed & 31) as usize] as char)?;
+        packed >>= 5;
+    }
+    Ok(())
+}
+
+pub(crate) fn parse_friend_code(s: &str) -> Result<u32, ParseError> {
+    (!s.is_empty()).then_some(()).ok_or(ParseError::Empty)?;
+    (s.len() >= CODE_LEN)
+        .then_some(())
+        .ok_or(ParseError::TooShort)?;
+    (s.len() == CODE_LEN && s.as_bytes()[DASH] == b'-')
+        .then_some(())
+        .ok_or(ParseError::UnknownFormat)?;
+
+    let mut code = 0u64;
+    let chars = s.bytes().enumerate().filter(|&(i, _)| i != DASH);
+    for (i, (_, b)) in chars.enumerate() {
+        let v = ALPHABET
+            .iter()
+            .position(|&c| c == b)
+            .ok_or(ParseError::UnknownFormat)?;
+        code |= (v as u64) << (5 * i);
+    }
+
+    let mut packed = (code << 20).swap_bytes();
+    let mut account_id = 0u32;
+    for _ in 0..8 {
+        packed >>= 1;
+        account_id = (account_id << 4) | (packed & 0xF) as u32;
+        packed >>= 4;
+    }
+
+    // Every code decodes to some account id, only the hash bits tell us if
+    // it was a real one.
+    (pack(account_id) >> 20 == code)
+        .then_some(account_id)
+        .ok_or(ParseError::Invalid(Field::AccountNumber))
+}
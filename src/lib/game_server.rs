@@ -0,0 +1,78 @@
+use alloc::string::String;
+
+use crate::errors::{Field, ParseError};
+use crate::SteamId;
+
+/// A persistent game server's [SteamId], along with the login token it
+/// logs on with, if any.
+///
+/// [GameServerId::new] checks the SteamId is a game server, see
+/// [SteamId::is_game_server].
+///
+/// With the `serde` feature this serializes as `{"id": ..., "token": ...}`,
+/// with the id as a SteamId64.
+/// # Example
+/// ```
+/// use steamid::{GameServerId, SteamIdBuilder};
+///
+/// let id = SteamIdBuilder::new().account_type('G').account_number(1).finish();
+/// let server = GameServerId::new(id, Some("token".to_string())).unwrap();
+/// assert_eq!(server.token.as_deref(), Some("token"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameServerId {
+    pub id: SteamId,
+    pub token: Option<String>,
+}
+
+impl GameServerId {
+    /// Pairs a game server's SteamId with its login token, failing with
+    /// [ParseError::Invalid] for [Field::AccountType] if `id` isn't a game
+    /// server.
+    pub fn new(id: SteamId, token: Option<String>) -> Result<GameServerId, ParseError> {
+        id.is_game_server()
+            .then_some(GameServerId { id, token })
+            .ok_or(ParseError::Invalid(Field::AccountType))
+    }
+}
+
+impl From<GameServerId> for SteamId {
+    fn from(server: GameServerId) -> Self {
+        server.id
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Unit Testing
+/////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::errors::{Field, ParseError};
+    use crate::*;
+
+    #[test]
+    fn rejects_non_servers() {
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(
+            GameServerId::new(user, None),
+            Err(ParseError::Invalid(Field::AccountType))
+        );
+        let clan = SteamId::from(103582791464489035);
+        assert!(GameServerId::new(clan, Some("token".into())).is_err());
+
+        let anon = SteamIdBuilder::anonymous_game_server().finish();
+        let server = GameServerId::new(anon, None).unwrap();
+        assert_eq!(SteamId::from(server), anon);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let id: SteamId = "[G:1:3]".parse().unwrap();
+        let server = GameServerId::new(id, Some("abc".into())).unwrap();
+        let json = serde_json::to_string(&server).unwrap();
+        assert_eq!(json, format!(r#"{{"id":{},"token":"abc"}}"#, u64::from(id)));
+        assert_eq!(serde_json::from_str::<GameServerId>(&json).unwrap(), server);
+    }
+}
@@ -1,3 +1,6 @@
+use core::fmt::Display;
+
+use crate::errors::{Field, ParseError};
 use crate::{mask, shift, SteamId};
 
 use super::ChatType;
@@ -9,6 +12,36 @@ pub enum Instance {
     Desktop(ChatType),
     Console(ChatType),
     Web(ChatType),
+    /// Any 20-bit instance value the other variants can't represent, kept
+    /// as-is so it isn't lost.
+    ///
+    /// Only created for such values, `Raw(1)` is never returned in place of
+    /// [Instance::Desktop].
+    Raw(u32),
+}
+
+impl Instance {
+    /// Converts a raw 20-bit instance value into an Instance, failing on
+    /// values with no matching variant rather than returning
+    /// [Instance::Raw] like [From] does.
+    ///
+    /// **Note**: `TryFrom<u32>` can't be used for this, as `From<u32>`
+    /// already provides an infallible one.
+    /// # Example
+    /// ```
+    /// use steamid::{ChatType, Instance};
+    ///
+    /// assert_eq!(Instance::try_from_raw(4), Ok(Instance::Web(ChatType::None)));
+    /// assert!(Instance::try_from_raw(3).is_err());
+    /// ```
+    pub fn try_from_raw(v: u32) -> Result<Instance, ParseError> {
+        match Instance::from(v) {
+            Instance::Raw(_) => Err(ParseError::Invalid(Field::Instance)),
+            instance => (u32::from(instance) == v)
+                .then_some(instance)
+                .ok_or(ParseError::Invalid(Field::Instance)),
+        }
+    }
 }
 
 impl Default for Instance {
@@ -17,6 +50,24 @@ impl Default for Instance {
     }
 }
 
+/// Writes the place, followed by the [ChatType] when there is one, e.g.
+/// `Desktop` or `None+ClanChat`. [Instance::Raw] writes its value.
+impl Display for Instance {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (place, chat_type) = match self {
+            Instance::None(v) => ("None", v),
+            Instance::Desktop(v) => ("Desktop", v),
+            Instance::Console(v) => ("Console", v),
+            Instance::Web(v) => ("Web", v),
+            Instance::Raw(v) => return write!(f, "{v}"),
+        };
+        match chat_type {
+            ChatType::None => f.write_str(place),
+            v => write!(f, "{place}+{v:?}"),
+        }
+    }
+}
+
 impl From<Instance> for u32 {
     #[rustfmt::skip]
     #[allow(clippy::identity_op)]
@@ -27,6 +78,7 @@ impl From<Instance> for u32 {
             Desktop(v)  => 1 | ((u8::from(v) as u32) << (shift::CHAT_TYPE - shift::INSTANCE)),
             Console(v)  => 2 | ((u8::from(v) as u32) << (shift::CHAT_TYPE - shift::INSTANCE)),
             Web(v)      => 4 | ((u8::from(v) as u32) << (shift::CHAT_TYPE - shift::INSTANCE)),
+            Raw(v)      => v & (mask::INSTANCE >> shift::INSTANCE) as u32,
         }
     }
 }
@@ -42,25 +94,35 @@ impl From<u32> for Instance {
         let chat_type = ChatType::from(((masked & mask::CHAT_TYPE) >> shift::CHAT_TYPE) as u8);
         // Remove the chat bits since we already extracted that.
         let masked_chat = (masked & (!mask::CHAT_TYPE)) >> shift::INSTANCE;
-        match masked_chat {
+        let instance = match masked_chat {
             0 => None(chat_type),
             1 => Desktop(chat_type),
             2 => Console(chat_type),
             4 => Web(chat_type),
-            // This is LOSSY!
-            // We can only represent values for which we have a discriminant.
-            _ => Desktop(chat_type),
+            _ => Raw(0),
+        };
+        // Anything the named variants can't represent exactly, including an
+        // unknown chat type, is kept raw.
+        match u32::from(instance) as u64 == masked >> shift::INSTANCE {
+            true => instance,
+            false => Raw((masked >> shift::INSTANCE) as u32),
         }
     }
 }
 
-impl From<SteamId> for Instance {
-    fn from(steamid: SteamId) -> Self {
+impl From<&SteamId> for Instance {
+    fn from(steamid: &SteamId) -> Self {
         let val = ((steamid.id & mask::INSTANCE) >> shift::INSTANCE) as u32;
         Instance::from(val)
     }
 }
 
+impl From<SteamId> for Instance {
+    fn from(steamid: SteamId) -> Self {
+        Instance::from(&steamid)
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////
 // Unit Testing
 /////////////////////////////////////////////////////////////////////////////
@@ -78,8 +140,50 @@ mod tests {
     fn value_conversion() {
         assert_eq!(Instance::from(0), Instance::None(ChatType::default()));
         assert_eq!(Instance::from(1), Instance::Desktop(ChatType::default()));
-        assert_eq!(Instance::from(3), Instance::Desktop(ChatType::default()));
-        assert_eq!(Instance::from(3), Instance::Desktop(ChatType::default()));
+        assert_eq!(Instance::from(3), Instance::Raw(3));
+        // Unknown chat type.
+        assert_eq!(Instance::from(3 << 12 | 1), Instance::Raw(3 << 12 | 1));
+        // Wider than 20 bits.
+        assert_eq!(
+            Instance::from(1 << 20 | 1),
+            Instance::Desktop(ChatType::None)
+        );
+        assert_eq!(Instance::from(1 << 20 | 3), Instance::Raw(3));
+    }
+
+    #[test]
+    fn raw_reciprocity() {
+        for v in [3, 5, 0xFFF, 1 << 12 | 7, 0xFFFFF] {
+            let instance = Instance::from(v);
+            assert_eq!(instance, Instance::Raw(v));
+            assert_eq!(u32::from(instance), v);
+        }
+
+        let lobby = SteamIdBuilder::new()
+            .account_type('L')
+            .instance(Instance::Raw(2 << 12 | 9))
+            .finish();
+        assert_eq!(lobby.instance(), Instance::Raw(2 << 12 | 9));
+        assert_eq!(SteamId::from(u64::from(lobby)), lobby);
+        assert_eq!(
+            SteamIdBuilder::from(lobby).finish().instance(),
+            lobby.instance()
+        );
+    }
+
+    #[test]
+    fn checked_conversion() {
+        for v in [0, 1, 2, 4, 1 << 12 | 1, 4 << 12 | 4] {
+            assert_eq!(Instance::try_from_raw(v), Ok(Instance::from(v)));
+        }
+        assert_eq!(
+            Instance::try_from_raw(3),
+            Err(errors::ParseError::Invalid(errors::Field::Instance))
+        );
+        // Unknown chat type.
+        assert!(Instance::try_from_raw(3 << 12 | 1).is_err());
+        // Wider than 20 bits.
+        assert!(Instance::try_from_raw(1 << 20 | 1).is_err());
     }
 
     #[test]
@@ -101,4 +205,22 @@ mod tests {
             Instance::Web(ChatType::None)
         );
     }
+
+    #[test]
+    fn instance_fmt_display() {
+        assert_eq!(Instance::Desktop(ChatType::None).to_string(), "Desktop");
+        assert_eq!(Instance::Console(ChatType::None).to_string(), "Console");
+        assert_eq!(Instance::Web(ChatType::None).to_string(), "Web");
+        assert_eq!(Instance::None(ChatType::None).to_string(), "None");
+        assert_eq!(
+            Instance::None(ChatType::ClanChat).to_string(),
+            "None+ClanChat"
+        );
+        assert_eq!(Instance::Web(ChatType::Lobby).to_string(), "Web+Lobby");
+        assert_eq!(
+            Instance::Desktop(ChatType::MatchMakingLobby).to_string(),
+            "Desktop+MatchMakingLobby"
+        );
+        assert_eq!(Instance::Raw(3).to_string(), "3");
+    }
 }
@@ -0,0 +1,47 @@
+// Steam invite codes, e.g. the `cv-dgb` in `https://s.team/p/cv-dgb`.
+//
+// The account id is written in hex with each digit swapped for a letter, and
+// a dash placed halfway through. Like friend codes it only covers the account
+// id, every other field is left to the caller.
+use core::fmt;
+
+use crate::errors::{Field, ParseError};
+
+// Replaces the hex digits `0123456789abcdef`, in order.
+const ALPHABET: &[u8; 16] = b"bcdfghjkmnpqrtvw";
+const INVITE_URL: &str = "https://s.team/p/";
+
+pub(crate) fn write_invite_code<W: fmt::Write>(w: &mut W, account_id: u32) -> fmt::Result {
+    let digits = ((32 - account_id.leading_zeros() as usize).div_ceil(4)).max(1);
+    for (pos, shift) in (0..digits).rev().map(|i| i * 4).enumerate() {
+        if pos > 0 && pos == digits / 2 {
+            w.write_char('-')?;
+        }
+        w.write_char(ALPHABET[((account_id >> shift) & 0xF) as usize] as char)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn parse_invite_code(s: &str) -> Result<u32, ParseError> {
+    let s = s.trim();
+    let code = s.strip_prefix(INVITE_URL).unwrap_or(s);
+    (!code.is_empty()).then_some(()).ok_or(ParseError::Empty)?;
+
+    let mut digits = 0;
+    let mut account_id = 0u32;
+    for b in code.bytes().filter(|&b| b != b'-') {
+        let v = ALPHABET
+            .iter()
+            .position(|&c| c == b)
+            .ok_or(ParseError::Invalid(Field::AccountNumber))?;
+        digits += 1;
+        // More than 8 digits can't fit in an account id.
+        (digits <= 8)
+            .then_some(())
+            .ok_or(ParseError::Invalid(Field::AccountNumber))?;
+        account_id = (account_id << 4) | v as u32;
+    }
+    (digits > 0)
+        .then_some(account_id)
+        .ok_or(ParseError::Invalid(Field::AccountNumber))
+}
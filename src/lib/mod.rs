@@ -28,6 +28,8 @@
 //! let user: SteamId = "STEAM_0:1:15344052".parse().unwrap();
 //! // From a SteamId64 string.
 //! let user: SteamId = "76561197990953833".parse().unwrap();
+//! // From a Steam Community URL.
+//! let user: SteamId = "https://steamcommunity.com/profiles/76561197990953833".parse().unwrap();
 //! ```
 //! # [SteamIdBuilder]
 //! Used to build or modify underlying values.
@@ -54,18 +56,53 @@
 //! println!("{}", IdFormat::Url(group));
 //! ```
 //!
+//! # Features
+//! * `std` *(default)*: Implements [std::error::Error] for the error types.
+//! * `alloc`: Everything returning a [String] or [Vec], such as the formatting
+//!   helpers and [render]. Without it only parsing, the builder, and the field
+//!   accessors are available, writing a SteamId is still possible through
+//!   [core::fmt::Display].
+//! * `serde`, `arbitrary`, `proptest`: Integrations with those crates, each
+//!   requires `std`.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 mod account_type;
+mod annotated;
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
 mod chat_type;
+mod components;
+mod era;
 pub mod errors;
+mod friend_code;
+#[cfg(feature = "alloc")]
+mod game_server;
 mod instance;
+mod invite_code;
+mod patch;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "alloc")]
+pub mod render;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod steam_id;
 mod universe;
+pub mod well_known;
 
 // Exports
 pub use account_type::*;
+pub use annotated::*;
 pub use chat_type::*;
+pub use components::*;
+pub use era::*;
+#[cfg(feature = "alloc")]
+pub use game_server::*;
 pub use instance::*;
+pub use patch::*;
 pub use steam_id::*;
 pub use universe::*;
 
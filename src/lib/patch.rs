@@ -0,0 +1,136 @@
+use crate::errors::Field;
+use crate::steam_id::field_mask;
+use crate::{SteamId, SteamIdBuilder};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+// Every field a patch can hold, from least to most significant.
+const FIELDS: [Field; 5] = [
+    Field::AuthServer,
+    Field::AccountNumber,
+    Field::Instance,
+    Field::AccountType,
+    Field::Universe,
+];
+
+/// The fields that differ between two SteamIds, made by [SteamId::delta].
+///
+/// Each differing [Field] is stored whole, with the value from the *other*
+/// SteamId, applying it with [SteamIdBuilder::apply_patch] overwrites that
+/// field and leaves every other field alone.
+///
+/// **Note**: The [ChatType](crate::ChatType) is part of [Field::Instance], so
+/// it's carried along with the instance rather than the account type.
+/// # Example
+/// ```
+/// use steamid::{SteamId, SteamIdBuilder, Universe};
+/// use steamid::errors::Field;
+///
+/// let a = SteamId::from(76561197990953833);
+/// let b = SteamIdBuilder::from(a).universe(Universe::Beta).finish();
+/// let patch = a.delta(&b);
+/// assert_eq!(patch.fields(), vec![Field::Universe]);
+///
+/// let c = SteamIdBuilder::new().account_number(1).apply_patch(&patch).finish();
+/// assert_eq!(c.universe(), Universe::Beta);
+/// assert_eq!(c.account_number(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SteamIdPatch {
+    mask: u64,
+    bits: u64,
+}
+
+impl SteamIdPatch {
+    /// Returns every [Field] this patch changes, from least to most
+    /// significant.
+    #[cfg(feature = "alloc")]
+    pub fn fields(&self) -> Vec<Field> {
+        FIELDS
+            .into_iter()
+            .filter(|&field| self.mask & field_mask(field) != 0)
+            .collect()
+    }
+
+    /// Returns whether this patch changes nothing, as when both SteamIds were
+    /// equal.
+    pub fn is_empty(&self) -> bool {
+        self.mask == 0
+    }
+}
+
+impl SteamId {
+    /// Returns a [SteamIdPatch] holding every field where `other` differs
+    /// from this SteamId, with `other`'s values.
+    pub fn delta(&self, other: &SteamId) -> SteamIdPatch {
+        let mask = FIELDS
+            .into_iter()
+            .map(field_mask)
+            .filter(|&mask| (self.id ^ other.id) & mask != 0)
+            .fold(0, |acc, mask| acc | mask);
+        SteamIdPatch {
+            mask,
+            bits: other.id & mask,
+        }
+    }
+}
+
+impl SteamIdBuilder {
+    /// Overwrites every field held by `patch`, see [SteamId::delta].
+    ///
+    /// Like [SteamIdBuilder::copy_field] this has no side-effects on other
+    /// fields.
+    pub fn apply_patch(mut self, patch: &SteamIdPatch) -> Self {
+        self.id = (self.id & !patch.mask) | patch.bits;
+        self.truncated &= !patch.mask;
+        self
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Unit Testing
+/////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::errors::Field;
+    use crate::*;
+
+    #[test]
+    fn universe_instance_delta() {
+        let a = SteamId::from(76561197990953833);
+        let b = SteamIdBuilder::from(a)
+            .universe(Universe::Beta)
+            .instance(Instance::Web(ChatType::None))
+            .finish();
+        let patch = a.delta(&b);
+        assert_eq!(patch.fields(), vec![Field::Instance, Field::Universe]);
+        assert_eq!(SteamIdBuilder::from(a).apply_patch(&patch).finish(), b);
+
+        let c: SteamId = "[g:1:34967627]".parse().unwrap();
+        let patched = SteamIdBuilder::from(c).apply_patch(&patch).finish();
+        assert_eq!(patched.universe(), Universe::Beta);
+        assert_eq!(patched.instance(), Instance::Web(ChatType::None));
+        assert_eq!(patched.account_type(), AccountType::Clan);
+        assert_eq!(patched.account_id(), c.account_id());
+    }
+
+    #[test]
+    fn empty_delta() {
+        let a = SteamId::from(76561197990953833);
+        let patch = a.delta(&a);
+        assert!(patch.is_empty());
+        assert!(patch.fields().is_empty());
+        assert_eq!(patch, SteamIdPatch::default());
+
+        let c = SteamId::from(103582791464489035);
+        assert_eq!(SteamIdBuilder::from(c).apply_patch(&patch).finish(), c);
+    }
+
+    #[test]
+    fn patch_clears_out_of_range() {
+        let a = SteamId::from(76561197990953833);
+        let patch = SteamId::from(0).delta(&a);
+        let over = SteamIdBuilder::new().account_number(1 << 31);
+        assert_eq!(over.apply_patch(&patch).try_finish(), Ok(a));
+    }
+}
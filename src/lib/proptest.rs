@@ -0,0 +1,120 @@
+//! [proptest](::proptest) strategies, behind the `proptest` feature.
+//!
+//! Every strategy only generates representable values, so the round trip
+//! laws of the formats can be checked without filtering.
+//! # Example
+//! ```
+//! use proptest::test_runner::TestRunner;
+//! use steamid::{IdFormat, SteamId};
+//!
+//! TestRunner::default()
+//!     .run(&steamid::proptest::any_steamid(), |id| {
+//!         let parsed: SteamId = IdFormat::SteamId64(id).to_string().parse().unwrap();
+//!         assert_eq!(parsed, id);
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! ```
+use ::proptest::prelude::*;
+
+use crate::{AccountType, ChatType, Instance, SteamId, SteamIdBuilder, Universe};
+
+/// Generates any [ChatType].
+pub fn chat_type() -> impl Strategy<Value = ChatType> {
+    prop_oneof![
+        Just(ChatType::None),
+        Just(ChatType::MatchMakingLobby),
+        Just(ChatType::Lobby),
+        Just(ChatType::ClanChat),
+    ]
+}
+
+/// Generates any [AccountType], including every [ChatType] of
+/// [AccountType::Chat].
+pub fn account_type() -> impl Strategy<Value = AccountType> {
+    prop_oneof![
+        (0..=10u8)
+            .prop_map(AccountType::from)
+            .prop_filter("chat types are generated separately", |v| {
+                !matches!(v, AccountType::Chat(_))
+            }),
+        chat_type().prop_map(AccountType::Chat),
+    ]
+}
+
+/// Generates any [Universe].
+pub fn universe() -> impl Strategy<Value = Universe> {
+    (0..=5u8).prop_map(Universe::from)
+}
+
+/// Generates any named [Instance], never [Instance::Raw].
+pub fn instance() -> impl Strategy<Value = Instance> {
+    (prop_oneof![Just(0), Just(1), Just(2), Just(4)], chat_type())
+        .prop_map(|(place, chat_type)| Instance::from(place | (u8::from(chat_type) as u32) << 12))
+}
+
+/// Generates SteamIds made of the other strategies in this module.
+///
+/// Mostly generates [Universe::Public] [AccountType::Individual] accounts,
+/// like [SteamId::from_account_id] makes, as those are what virtually all
+/// real SteamIds are, but every other account type still comes up.
+pub fn any_steamid() -> impl Strategy<Value = SteamId> {
+    prop_oneof![
+        3 => any::<u32>().prop_map(SteamId::from_account_id),
+        1 => (universe(), account_type(), instance(), any::<u32>()).prop_map(
+            |(universe, account_type, instance, account_id)| {
+                let builder = SteamIdBuilder::new()
+                    .universe(universe)
+                    .account_type_preserve_bits(account_type)
+                    .instance(instance)
+                    .account_number((account_id >> 1) as u64)
+                    .authentication_server((account_id & 1) as u64);
+                // The chat type is read from the instance.
+                match account_type {
+                    AccountType::Chat(chat_type) => builder.chat_type(chat_type),
+                    _ => builder,
+                }
+                .finish()
+            }
+        ),
+    ]
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Unit Testing
+/////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use ::proptest::prelude::*;
+
+    use crate::*;
+
+    proptest! {
+        #[test]
+        fn steamid3_round_trip(id in super::any_steamid()) {
+            let formatted = IdFormat::SteamId3(id).to_string();
+            let parsed: SteamId = formatted.parse().unwrap();
+            // SteamId3 drops the instance of most account types, so only the
+            // fields it holds are compared.
+            prop_assert_eq!(IdFormat::SteamId3(parsed).to_string(), formatted);
+            prop_assert_eq!(parsed.account_id(), id.account_id());
+            // The type letter is lossy too, e.g. console users are written as `I`.
+            prop_assert_eq!(
+                parsed.account_type(),
+                AccountType::from(id.account_type().steam3_letter())
+            );
+            prop_assert_eq!(parsed.universe(), id.universe());
+        }
+
+        #[test]
+        fn representable_fields(
+            account_type in super::account_type(),
+            universe in super::universe(),
+            instance in super::instance(),
+        ) {
+            prop_assert!(AccountType::try_from_raw(u8::from(account_type)).is_ok());
+            prop_assert_eq!(Universe::try_from_raw(u8::from(universe)), Ok(universe));
+            prop_assert_eq!(Instance::try_from_raw(u32::from(instance)), Ok(instance));
+        }
+    }
+}
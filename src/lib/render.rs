@@ -0,0 +1,163 @@
+//! Formatting a [SteamId] into a format chosen at compile time.
+//!
+//! [IdFormat](crate::IdFormat) picks the output format at runtime, which is
+//! what you want most of the time. When exporting a large number of ids into a
+//! single known format, [render] can be used instead so the compiler only emits
+//! the code path for that one format.
+//!
+//! # Example
+//! ```
+//! use steamid::render::{render, STEAMID3};
+//! use steamid::SteamId;
+//!
+//! let user = SteamId::from(76561197990953833);
+//! assert_eq!(render::<STEAMID3>(&user), "[U:1:30688105]");
+//! ```
+use crate::steam_id::{
+    write_steamid2, write_steamid2_legacy, write_steamid3, write_steamid64, write_url,
+};
+use crate::SteamId;
+use alloc::string::String;
+
+/// Same output as [IdFormat::SteamId64](crate::IdFormat::SteamId64).
+pub const STEAMID64: u8 = 0;
+/// Same output as [IdFormat::SteamId2](crate::IdFormat::SteamId2).
+pub const STEAMID2: u8 = 1;
+/// Same output as [IdFormat::SteamId2Legacy](crate::IdFormat::SteamId2Legacy).
+pub const STEAMID2_LEGACY: u8 = 2;
+/// Same output as [IdFormat::SteamId3](crate::IdFormat::SteamId3).
+pub const STEAMID3: u8 = 3;
+/// Same output as [IdFormat::Url](crate::IdFormat::Url).
+pub const URL: u8 = 4;
+
+/// Formats `id` as the format `F`, one of the constants in this module.
+///
+/// Using any other value for `F` fails to compile.
+pub fn render<const F: u8>(id: &SteamId) -> String {
+    const { assert!(F <= URL, "unknown render format") };
+    // Large enough for the longest format, a group URL.
+    let mut out = String::with_capacity(64);
+    let result = match F {
+        STEAMID64 => write_steamid64(&mut out, id),
+        STEAMID2 => write_steamid2(&mut out, id),
+        STEAMID2_LEGACY => write_steamid2_legacy(&mut out, id),
+        STEAMID3 => write_steamid3(&mut out, id),
+        _ => write_url(&mut out, id),
+    };
+    // Writing into a String can't fail.
+    result.expect("formatting into a String failed");
+    out
+}
+
+/// A reusable buffer for formatting many SteamIds without allocating each
+/// time.
+///
+/// Each method clears the buffer, writes the SteamId into it and returns the
+/// result, which stays borrowed until the next call.
+///
+/// # Example
+/// ```
+/// use steamid::render::FormatBuffer;
+/// use steamid::SteamId;
+///
+/// let mut buffer = FormatBuffer::new();
+/// for raw in [76561197990953833, 76561198930384249] {
+///     let steam3 = buffer.steamid3(&SteamId::from(raw));
+///     println!("{steam3}");
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FormatBuffer {
+    buffer: String,
+}
+
+impl FormatBuffer {
+    /// Creates a new buffer, large enough for any format.
+    pub fn new() -> Self {
+        FormatBuffer {
+            buffer: String::with_capacity(64),
+        }
+    }
+
+    /// Same output as [IdFormat::SteamId64](crate::IdFormat::SteamId64).
+    pub fn steamid64(&mut self, id: &SteamId) -> &str {
+        self.write(id, write_steamid64)
+    }
+
+    /// Same output as [IdFormat::SteamId2](crate::IdFormat::SteamId2).
+    pub fn steamid2(&mut self, id: &SteamId) -> &str {
+        self.write(id, write_steamid2)
+    }
+
+    /// Same output as [IdFormat::SteamId2Legacy](crate::IdFormat::SteamId2Legacy).
+    pub fn steamid2_legacy(&mut self, id: &SteamId) -> &str {
+        self.write(id, write_steamid2_legacy)
+    }
+
+    /// Same output as [IdFormat::SteamId3](crate::IdFormat::SteamId3).
+    pub fn steamid3(&mut self, id: &SteamId) -> &str {
+        self.write(id, write_steamid3)
+    }
+
+    /// Same output as [IdFormat::Url](crate::IdFormat::Url).
+    pub fn url(&mut self, id: &SteamId) -> &str {
+        self.write(id, write_url)
+    }
+
+    fn write(&mut self, id: &SteamId, f: fn(&mut String, &SteamId) -> core::fmt::Result) -> &str {
+        self.buffer.clear();
+        // Writing into a String can't fail.
+        f(&mut self.buffer, id).expect("formatting into a String failed");
+        &self.buffer
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Unit Testing
+/////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::render::*;
+    use crate::*;
+
+    #[test]
+    fn format_buffer() {
+        let mut buffer = FormatBuffer::new();
+        let user = SteamId::from(76561197990953833);
+        let clan = SteamId::from(103582791464489035);
+
+        assert_eq!(buffer.steamid3(&user), "[U:1:30688105]");
+        assert_eq!(buffer.steamid3(&clan), "[g:1:34967627]");
+        assert_eq!(buffer.steamid64(&user), "76561197990953833");
+        assert_eq!(buffer.steamid2(&user), "STEAM_1:1:15344052");
+        assert_eq!(buffer.steamid2_legacy(&user), "STEAM_0:1:15344052");
+        assert_eq!(buffer.url(&clan), IdFormat::Url(clan).to_string());
+        // Shorter output doesn't leave anything behind.
+        assert_eq!(buffer.steamid64(&SteamId::from(0)), "0");
+    }
+
+    /// Ensures every format renders identically to its [IdFormat] counterpart.
+    #[test]
+    fn matches_id_format() {
+        let ids = [
+            SteamId::from(76561197990953833),
+            SteamId::from(103582791464489035),
+            SteamId::from(85568392923371047),
+            SteamId::from(0),
+            SteamId::from(u64::MAX),
+        ];
+        for id in ids {
+            assert_eq!(
+                render::<STEAMID64>(&id),
+                IdFormat::SteamId64(id).to_string()
+            );
+            assert_eq!(render::<STEAMID2>(&id), IdFormat::SteamId2(id).to_string());
+            assert_eq!(
+                render::<STEAMID2_LEGACY>(&id),
+                IdFormat::SteamId2Legacy(id).to_string()
+            );
+            assert_eq!(render::<STEAMID3>(&id), IdFormat::SteamId3(id).to_string());
+            assert_eq!(render::<URL>(&id), IdFormat::Url(id).to_string());
+        }
+    }
+}
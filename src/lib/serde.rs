@@ -0,0 +1,192 @@
+//! [serde] support, enabled with the `serde` feature.
+//!
+//! By default a [SteamId] serializes as its raw SteamId64 number. When
+//! deserializing, both a SteamId64 number and any string [SteamId]'s
+//! [FromStr] accepts are allowed.
+//!
+//! Use [as_steamid3] with `#[serde(with = "...")]` to write the SteamId3
+//! string form instead.
+//!
+//! # Example
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use steamid::SteamId;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Player {
+//!     id: SteamId,
+//!     #[serde(with = "steamid::serde::as_steamid3")]
+//!     id3: SteamId,
+//! }
+//! ```
+//!
+//! **Note**: Accepting both shapes relies on `deserialize_any`, so only
+//! self-describing formats such as JSON are supported.
+use std::fmt;
+use std::str::FromStr;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::{Deserialize, Serialize, Serializer};
+
+use crate::errors::{Field, ParseError};
+use crate::{IdFormat, SteamId};
+
+impl Serialize for SteamId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.id)
+    }
+}
+
+impl<'de> Deserialize<'de> for SteamId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SteamIdVisitor)
+    }
+}
+
+/// Accepts a SteamId64 number, or any string [SteamId::from_str] does.
+struct SteamIdVisitor;
+
+impl<'de> Visitor<'de> for SteamIdVisitor {
+    type Value = SteamId;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a SteamId64 number or a SteamId string")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(SteamId::from(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        u64::try_from(v)
+            .map(SteamId::from)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        SteamId::from_str(v).map_err(E::custom)
+    }
+}
+
+impl SteamId {
+    /// Reads a SteamId from a JSON number or string, for walking arbitrary
+    /// JSON without deriving [Deserialize].
+    ///
+    /// Numbers must be a SteamId64, strings accept anything
+    /// [SteamId::from_str] does.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let json: serde_json::Value = serde_json::from_str(r#"["[U:1:30688105]"]"#).unwrap();
+    /// let user = SteamId::from_json(&json[0]).unwrap();
+    /// assert_eq!(u64::from(user), 76561197990953833);
+    /// ```
+    pub fn from_json(value: &serde_json::Value) -> Result<SteamId, ParseError> {
+        match value {
+            serde_json::Value::Number(n) => n
+                .as_u64()
+                .map(SteamId::from)
+                .ok_or(ParseError::Invalid(Field::SteamId64)),
+            serde_json::Value::String(s) => s.parse(),
+            _ => Err(ParseError::UnknownFormat),
+        }
+    }
+}
+
+/// Serializes a [SteamId] as its [IdFormat::SteamId3] string.
+///
+/// Deserializing accepts the same inputs as [SteamId] itself.
+///
+/// Use with `#[serde(with = "steamid::serde::as_steamid3")]`.
+pub mod as_steamid3 {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(id: &SteamId, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&IdFormat::SteamId3(*id))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SteamId, D::Error> {
+        SteamId::deserialize(deserializer)
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Unit Testing
+/////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+
+    use crate::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Player {
+        id: SteamId,
+        #[serde(with = "crate::serde::as_steamid3")]
+        id3: SteamId,
+    }
+
+    #[test]
+    fn round_trip() {
+        let user = SteamId::from(76561197990953833);
+        let player = Player {
+            id: user,
+            id3: user,
+        };
+        let json = serde_json::to_string(&player).unwrap();
+        assert_eq!(json, r#"{"id":76561197990953833,"id3":"[U:1:30688105]"}"#);
+        assert_eq!(serde_json::from_str::<Player>(&json).unwrap(), player);
+    }
+
+    #[test]
+    fn accepts_both_shapes() {
+        let user = SteamId::from(76561197990953833);
+        for json in [
+            r#"{"id":"76561197990953833","id3":76561197990953833}"#,
+            r#"{"id":"STEAM_1:1:15344052","id3":"[U:1:30688105]"}"#,
+        ] {
+            let player: Player = serde_json::from_str(json).unwrap();
+            assert_eq!(
+                player,
+                Player {
+                    id: user,
+                    id3: user
+                }
+            );
+        }
+
+        assert!(serde_json::from_str::<SteamId>(r#""not an id""#).is_err());
+        assert!(serde_json::from_str::<SteamId>("-1").is_err());
+        assert!(serde_json::from_str::<SteamId>("1.5").is_err());
+    }
+
+    #[test]
+    fn from_json() {
+        use crate::errors::{Field, ParseError};
+        use serde_json::json;
+
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(SteamId::from_json(&json!(76561197990953833u64)), Ok(user));
+        assert_eq!(SteamId::from_json(&json!("76561197990953833")), Ok(user));
+        assert_eq!(SteamId::from_json(&json!("STEAM_1:1:15344052")), Ok(user));
+
+        assert_eq!(
+            SteamId::from_json(&json!(-1)),
+            Err(ParseError::Invalid(Field::SteamId64))
+        );
+        assert_eq!(
+            SteamId::from_json(&json!(1.5)),
+            Err(ParseError::Invalid(Field::SteamId64))
+        );
+        assert_eq!(SteamId::from_json(&json!("")), Err(ParseError::Empty));
+        assert_eq!(
+            SteamId::from_json(&json!(null)),
+            Err(ParseError::UnknownFormat)
+        );
+        assert_eq!(
+            SteamId::from_json(&json!([1])),
+            Err(ParseError::UnknownFormat)
+        );
+    }
+}
@@ -1,14 +1,22 @@
-const PROFILE_URL: &str = "http://steamcommunity.com/profiles/";
-const GROUP_URL: &str = "http://steamcommunity.com/gid/";
+const COMMUNITY_HOST: &str = "steamcommunity.com";
+const PROFILE_PATH: &str = "/profiles/";
+const GROUP_PATH: &str = "/gid/";
+const OPENID_URL: &str = "https://steamcommunity.com/openid/id/";
+const FRIEND_ADD_URI: &str = "steam://friends/add/";
+const JOIN_CHAT_URI: &str = "steam://friends/joinchat/";
 
-use std::fmt::{Debug, Display};
-use std::str::FromStr;
+use core::fmt::{self, Debug, Display};
+use core::str::FromStr;
 
 use crate::account_type::AccountType;
-use crate::errors::{Field, ParseError};
+use crate::errors::{BuildError, Field, ParseError};
+use crate::friend_code::{parse_friend_code, write_friend_code};
+use crate::invite_code::{parse_invite_code, write_invite_code};
 use crate::universe::Universe;
 use crate::{mask, shift};
-use crate::{ChatType, Instance};
+use crate::{ChatType, Era, Instance};
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, string::ToString, vec::Vec};
 
 /// Replaces the bits in `val` with those from `new`, leaving masked bits alone.
 #[inline(always)]
@@ -16,6 +24,18 @@ fn replace_bits(val: u64, mask: u64, new: u64) -> u64 {
     (val & (!mask)) | (new & mask)
 }
 
+/// Returns the mask covering a field's bits, [Field::SteamId64] covers all of them.
+pub(crate) fn field_mask(field: Field) -> u64 {
+    match field {
+        Field::AuthServer => mask::AUTH_SERVER,
+        Field::AccountNumber => mask::ACCOUNT_NUMBER,
+        Field::Instance => mask::INSTANCE,
+        Field::AccountType => mask::ACCOUNT_TYPE,
+        Field::Universe => mask::UNIVERSE,
+        Field::SteamId64 => u64::MAX,
+    }
+}
+
 /// Used to build a new SteamId from values.
 ///
 /// # Examples #
@@ -55,9 +75,17 @@ fn replace_bits(val: u64, mask: u64, new: u64) -> u64 {
 #[derive(Debug, Clone, Copy)]
 pub struct SteamIdBuilder {
     pub(crate) id: u64,
+    /// Masks of the fields whose last set value didn't fit, see
+    /// [SteamIdBuilder::try_finish].
+    pub(crate) truncated: u64,
 }
 
 impl SteamIdBuilder {
+    const BLANK: SteamIdBuilder = SteamIdBuilder {
+        id: 0,
+        truncated: 0,
+    };
+
     #[allow(clippy::new_without_default)]
     /// Begets a new SteamIdBuilder with some resonable defaults.
     ///
@@ -66,7 +94,7 @@ impl SteamIdBuilder {
     /// - Universe = [Universe::Public]
     /// - Instance = [Instance::Desktop]
     pub fn new() -> Self {
-        SteamIdBuilder { id: 0 }
+        Self::BLANK
             .account_type(AccountType::Individual)
             .universe(Universe::Public)
             // If we don't set instance to 1 here then we won't match 3rd party
@@ -74,6 +102,32 @@ impl SteamIdBuilder {
             .instance(1)
     }
 
+    /// Begets a new SteamIdBuilder for a blank anonymous game server logon.
+    ///
+    /// This matches what Valve uses when a dedicated server logs on
+    /// anonymously, such as through steamcmd, the account number is left for
+    /// Steam to assign.
+    ///
+    /// Defaults:
+    /// - Account Type = [AccountType::AnonGameServer]
+    /// - Universe = [Universe::Public]
+    /// - Instance = [Instance::None]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::{SteamIdBuilder, IdFormat};
+    ///
+    /// let server = SteamIdBuilder::anonymous_game_server().finish();
+    /// assert_eq!(IdFormat::SteamId3(server).to_string(), "[A:1:0:0]");
+    /// ```
+    pub fn anonymous_game_server() -> Self {
+        Self::BLANK
+            .universe(Universe::Public)
+            // Also resets the instance to `None`, as Valve does.
+            .account_type(AccountType::AnonGameServer)
+    }
+
     /// Consumes the SteamIdBuilder and returns a new SteamId.
     ///
     /// # Example
@@ -92,12 +146,52 @@ impl SteamIdBuilder {
         SteamId { id: self.id }
     }
 
+    /// Finalizes the builder like [SteamIdBuilder::finish], but fails instead
+    /// of returning a SteamId with truncated or invalid fields.
+    ///
+    /// Values that didn't fit in their field, such as an account number over
+    /// `2^31`, are reported first as [BuildError::OutOfRange], followed by
+    /// the first issue found by [SteamIdBuilder::validate].
+    ///
+    /// **Note**: An individual account number of `0` is not an error here,
+    /// as it's the builder's default, only [SteamIdBuilder::validate]
+    /// reports it.
+    /// # Example
+    /// ```
+    /// use steamid::SteamIdBuilder;
+    /// use steamid::errors::{BuildError, Field};
+    ///
+    /// let user = SteamIdBuilder::new().account_number(1 << 31).try_finish();
+    /// assert_eq!(user, Err(BuildError::OutOfRange(Field::AccountNumber)));
+    ///
+    /// let user = SteamIdBuilder::new().account_number(1).try_finish();
+    /// assert_eq!(user.unwrap().account_number(), 1);
+    ///
+    /// assert!(SteamIdBuilder::new().try_finish().is_ok());
+    /// ```
+    pub fn try_finish(self) -> Result<SteamId, BuildError> {
+        [Field::AuthServer, Field::AccountNumber]
+            .into_iter()
+            .find(|&field| self.truncated & field_mask(field) != 0)
+            .map_or(Ok(()), |field| Err(BuildError::OutOfRange(field)))?;
+        match self.issues().into_iter().flatten().next() {
+            Some((field, reason)) => Err(BuildError::Invalid(field, reason)),
+            None => Ok(self.finish()),
+        }
+    }
+
     /// Sets the Authentication server bit
     ///
-    /// Only meaningful values are `0` or `1`, anything `> 1` is capped to `1`.
+    /// Only meaningful values are `0` or `1`, anything `> 1` is capped to `1`,
+    /// which [SteamIdBuilder::try_finish] reports.
     pub fn authentication_server(mut self, val: u64) -> Self {
         let new_val = val.min(1);
         self.id = replace_bits(self.id, mask::AUTH_SERVER, new_val << shift::AUTH_SERVER);
+        self.truncated = replace_bits(
+            self.truncated,
+            mask::AUTH_SERVER,
+            if new_val != val { mask::AUTH_SERVER } else { 0 },
+        );
         self
     }
 
@@ -106,9 +200,39 @@ impl SteamIdBuilder {
     /// This is what is visualized in the [IdFormat::SteamId2] format.  E.G
     /// `[U:1:3]` is account number `1` (`STEAM_1:1:1`)
     ///
-    /// **Notice**: Values exceeding `2^31` are truncated at the highest bit.
+    /// **Notice**: Values exceeding `2^31` are truncated at the highest bit,
+    /// use [SteamIdBuilder::try_finish] to catch this.
     pub fn account_number(mut self, val: u64) -> Self {
         self.id = replace_bits(self.id, mask::ACCOUNT_NUMBER, val << shift::ACCOUNT_NUMBER);
+        let fits = val <= mask::ACCOUNT_NUMBER >> shift::ACCOUNT_NUMBER;
+        self.truncated = replace_bits(
+            self.truncated,
+            mask::ACCOUNT_NUMBER,
+            if fits { 0 } else { mask::ACCOUNT_NUMBER },
+        );
+        self
+    }
+
+    /// Sets the authentication server bit and account number together from
+    /// a 32-bit account id, leaving every other field alone.
+    ///
+    /// Unlike [SteamId::from_account_id] this can be used with any
+    /// [AccountType].
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::{SteamIdBuilder, IdFormat};
+    ///
+    /// let clan = SteamIdBuilder::new()
+    ///     .account_type('g')
+    ///     .combined_accountid(34967627)
+    ///     .finish();
+    /// assert_eq!(IdFormat::SteamId3(clan).to_string(), "[g:1:34967627]");
+    /// ```
+    pub fn combined_accountid(mut self, id: u32) -> Self {
+        self.id = replace_bits(self.id, mask::AUTH_SERVER | mask::ACCOUNT_NUMBER, id as u64);
+        // A u32 always fits, so any earlier out of range value is gone.
+        self.truncated &= !(mask::AUTH_SERVER | mask::ACCOUNT_NUMBER);
         self
     }
 
@@ -172,6 +296,92 @@ impl SteamIdBuilder {
         self
     }
 
+    /// Sets the instance to [Instance::Desktop], keeping the current [ChatType].
+    pub fn desktop(self) -> Self {
+        self.instance_place(Instance::Desktop(ChatType::None))
+    }
+
+    /// Sets the instance to [Instance::Console], keeping the current [ChatType].
+    pub fn console(self) -> Self {
+        self.instance_place(Instance::Console(ChatType::None))
+    }
+
+    /// Sets the instance to [Instance::Web], keeping the current [ChatType].
+    pub fn web(self) -> Self {
+        self.instance_place(Instance::Web(ChatType::None))
+    }
+
+    /// Sets the instance to [Instance::None], keeping the current [ChatType].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::{SteamIdBuilder, Instance, ChatType};
+    ///
+    /// let chat = SteamIdBuilder::new().account_type('c').web().none_instance().finish();
+    /// assert_eq!(chat.instance(), Instance::None(ChatType::ClanChat));
+    /// ```
+    pub fn none_instance(self) -> Self {
+        self.instance_place(Instance::None(ChatType::None))
+    }
+
+    /// Sets the [ChatType], this can either be a ChatType itself, or any
+    /// value which can be converted.
+    ///
+    /// Only the chat bits of the [Instance] are changed, the place is left
+    /// alone, and unlike `account_type` the account type isn't touched.
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::{SteamIdBuilder, Instance, ChatType};
+    ///
+    /// let chat = SteamIdBuilder::new()
+    ///     .account_type_preserve_bits('c')
+    ///     .chat_type(ChatType::Lobby)
+    ///     .finish();
+    /// assert_eq!(chat.instance(), Instance::Desktop(ChatType::Lobby));
+    /// ```
+    pub fn chat_type<T: Into<ChatType>>(mut self, val: T) -> Self {
+        let val = u8::from(val.into()) as u64;
+        self.id = replace_bits(self.id, mask::CHAT_TYPE, val << shift::CHAT_TYPE);
+        self
+    }
+
+    /// Sets both halves of a chat [Instance], the [ChatType] and the 12-bit
+    /// place below it, the inverse of [SteamId::chat_instance_parts].
+    ///
+    /// **Notice**: Places exceeding `2^12` are truncated at the highest bit.
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::{SteamIdBuilder, ChatType};
+    ///
+    /// let chat = SteamIdBuilder::new()
+    ///     .account_type('c')
+    ///     .chat_instance_parts(ChatType::Lobby, 2)
+    ///     .finish();
+    /// assert_eq!(chat.chat_instance_parts(), (ChatType::Lobby, 2));
+    /// ```
+    pub fn chat_instance_parts(mut self, chat_type: ChatType, place: u32) -> Self {
+        self.id = replace_bits(
+            self.id,
+            mask::INSTANCE & !mask::CHAT_TYPE,
+            (place as u64) << shift::INSTANCE,
+        );
+        self.chat_type(chat_type)
+    }
+
+    /// Replaces the instance bits below the [ChatType].
+    fn instance_place(mut self, place: Instance) -> Self {
+        let place = u32::from(place) as u64;
+        self.id = replace_bits(
+            self.id,
+            mask::INSTANCE & !mask::CHAT_TYPE,
+            place << shift::INSTANCE,
+        );
+        self
+    }
+
     /// Sets the [Universe] this account exists within.
     /// or any value which can be converted.
     pub fn universe<T: Into<Universe>>(mut self, val: T) -> Self {
@@ -180,11 +390,143 @@ impl SteamIdBuilder {
         self.id = replace_bits(self.id, mask::UNIVERSE, val << shift::UNIVERSE);
         self
     }
+
+    /// Copies the bits of `field` from another SteamId, leaving every other
+    /// field alone.
+    ///
+    /// Unlike the other setters this has no side-effects, so copying the
+    /// [Field::AccountType] won't change the [Field::Instance].
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::{SteamId, SteamIdBuilder, Universe};
+    /// use steamid::errors::Field;
+    ///
+    /// let beta = SteamIdBuilder::new().universe(Universe::Beta).finish();
+    /// let user = SteamIdBuilder::new()
+    ///     .account_number(1)
+    ///     .copy_field(Field::Universe, &beta)
+    ///     .finish();
+    /// assert_eq!(user.universe(), Universe::Beta);
+    /// ```
+    pub fn copy_field(mut self, field: Field, from: &SteamId) -> Self {
+        self.id = replace_bits(self.id, field_mask(field), from.id);
+        self.truncated &= !field_mask(field);
+        self
+    }
+
+    /// Returns every [Field] whose value differs from [SteamIdBuilder::new]'s
+    /// defaults.
+    ///
+    /// **Note**: Setting a field to its default value is not detected, and
+    /// [SteamIdBuilder::account_type] may also change the [Field::Instance].
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::SteamIdBuilder;
+    /// use steamid::errors::Field;
+    ///
+    /// let user = SteamIdBuilder::new().account_number(1);
+    /// assert_eq!(user.set_fields(), vec![Field::AccountNumber]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn set_fields(&self) -> Vec<Field> {
+        let defaults = SteamIdBuilder::new().id;
+        [
+            (Field::AuthServer, mask::AUTH_SERVER),
+            (Field::AccountNumber, mask::ACCOUNT_NUMBER),
+            (Field::Instance, mask::INSTANCE),
+            (Field::AccountType, mask::ACCOUNT_TYPE),
+            (Field::Universe, mask::UNIVERSE),
+        ]
+        .into_iter()
+        .filter(|&(_, mask)| self.id & mask != defaults & mask)
+        .map(|(field, _)| field)
+        .collect()
+    }
+
+    /// Checks the current values and returns every problem found, along with
+    /// the [Field] it's in. An empty result means the SteamId is valid.
+    ///
+    /// Checks performed:
+    /// - The account type is known, and not [AccountType::Invalid].
+    /// - The universe is known.
+    /// - The instance is one of the known [Instance] values.
+    /// - The instance only has chat bits set for chat accounts, and they're a
+    ///   known [ChatType].
+    /// - Individual accounts don't have an account number of `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::SteamIdBuilder;
+    /// use steamid::errors::Field;
+    ///
+    /// let user = SteamIdBuilder::new().account_number(1);
+    /// assert!(user.validate().is_empty());
+    ///
+    /// let invalid = user.account_type('I');
+    /// assert_eq!(invalid.validate()[0].0, Field::AccountType);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn validate(&self) -> Vec<(Field, &'static str)> {
+        self.issues()
+            .into_iter()
+            .chain([self.zero_account_issue()])
+            .flatten()
+            .collect()
+    }
+
+    /// Every check from [SteamIdBuilder::validate] used by
+    /// [SteamIdBuilder::try_finish], in order, without allocating.
+    fn issues(&self) -> [Option<(Field, &'static str)>; 4] {
+        let account_type = ((self.id & mask::ACCOUNT_TYPE) >> shift::ACCOUNT_TYPE) as u8;
+        let universe = ((self.id & mask::UNIVERSE) >> shift::UNIVERSE) as u8;
+        let chat_type = ((self.id & mask::CHAT_TYPE) >> shift::CHAT_TYPE) as u8;
+        let place = (self.id & mask::INSTANCE & !mask::CHAT_TYPE) >> shift::INSTANCE;
+
+        // Unknown values all convert to a default, so compare round trips.
+        let account_type_issue = match AccountType::from(account_type) {
+            AccountType::Invalid if account_type == 0 => {
+                Some((Field::AccountType, "account type is invalid"))
+            }
+            v if u8::from(v) != account_type => Some((Field::AccountType, "unknown account type")),
+            _ => None,
+        };
+        let chat_type_issue = if u8::from(ChatType::from(chat_type)) != chat_type {
+            Some((Field::Instance, "unknown chat type"))
+        } else if chat_type != 0 && !matches!(AccountType::from(account_type), AccountType::Chat(_))
+        {
+            Some((Field::Instance, "chat type set on a non-chat account"))
+        } else {
+            None
+        };
+        [
+            account_type_issue,
+            (u8::from(Universe::from(universe)) != universe)
+                .then_some((Field::Universe, "unknown universe")),
+            (!matches!(place, 0 | 1 | 2 | 4)).then_some((Field::Instance, "unknown instance")),
+            chat_type_issue,
+        ]
+    }
+
+    /// The last check from [SteamIdBuilder::validate], left out of
+    /// [SteamIdBuilder::try_finish] since a new builder starts at zero.
+    #[cfg(feature = "alloc")]
+    fn zero_account_issue(&self) -> Option<(Field, &'static str)> {
+        let account_type = ((self.id & mask::ACCOUNT_TYPE) >> shift::ACCOUNT_TYPE) as u8;
+        (AccountType::from(account_type) == AccountType::Individual
+            && self.id & (mask::ACCOUNT_NUMBER | mask::AUTH_SERVER) == 0)
+            .then_some((Field::AccountNumber, "individual account number is zero"))
+    }
 }
 
 impl From<SteamId> for SteamIdBuilder {
     fn from(steamid: SteamId) -> Self {
-        SteamIdBuilder { id: steamid.id }
+        SteamIdBuilder {
+            id: steamid.id,
+            truncated: 0,
+        }
     }
 }
 
@@ -192,21 +534,84 @@ impl FromStr for SteamIdBuilder {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let s = s.trim();
-        // No valid SteamId string can be longer than 32 bytes.
-        (s.len() < 32)
-            .then(||
-            // Only ever ASCII values in a SteamId so treat as bytes for speed.
-            match s.as_bytes().first().ok_or(ParseError::Empty)? {
-                b'0'..=b'9' => parse_from_steamid64(s),
-                b'S' => parse_from_steamid2(s),
-                b'[' => parse_from_steamid3(s),
-                _ => Err(ParseError::UnknownFormat),
-            })
-            .ok_or(ParseError::UnknownFormat)?
+        parse(s, Mode::Default)
+    }
+}
+
+/// How forgiving parsing should be of non-standard input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Default,
+    /// See [SteamId::from_str_lenient].
+    Lenient,
+    /// See [SteamId::parse_strict].
+    Strict,
+}
+
+fn parse(s: &str, mode: Mode) -> Result<SteamIdBuilder, ParseError> {
+    let s = match mode {
+        Mode::Lenient => unquote(s),
+        _ => s.trim(),
+    };
+    // Only ever ASCII values in a SteamId so treat as bytes for speed.
+    match s.as_bytes().first().ok_or(ParseError::Empty)? {
+        // Community URLs are the only input longer than 32 bytes.
+        b'h' => parse_from_url(s, mode),
+        // No other valid SteamId string can be longer than 32 bytes.
+        _ if s.len() >= 32 => Err(ParseError::UnknownFormat),
+        b'0'..=b'9' => parse_from_steamid64(s),
+        b'S' => parse_from_steamid2(s, mode),
+        b'[' => parse_from_steamid3(s, mode),
+        _ => Err(ParseError::UnknownFormat),
+    }
+    .and_then(|v| match mode {
+        Mode::Strict => check_known_values(v),
+        _ => Ok(v),
+    })
+}
+
+/// Repeatedly removes a surrounding pair of `"` or `\"` from the trimmed
+/// input, undoing ids that were quoted more than once.
+fn unquote(s: &str) -> &str {
+    let mut s = s.trim();
+    while let Some(inner) = s
+        .strip_prefix("\\\"")
+        .and_then(|v| v.strip_suffix("\\\""))
+        .or_else(|| s.strip_prefix('"').and_then(|v| v.strip_suffix('"')))
+    {
+        s = inner.trim();
+    }
+    s
+}
+
+/// Parses a universe field, rejecting values [Universe] can't represent
+/// when strict.
+fn parse_universe(s: &str, mode: Mode) -> Result<u8, ParseError> {
+    let universe = u8::from_str(s).map_err(|_| ParseError::Invalid(Field::Universe))?;
+    match mode {
+        Mode::Strict if u8::from(Universe::from(universe)) != universe => {
+            Err(ParseError::Invalid(Field::Universe))
+        }
+        _ => Ok(universe),
     }
 }
 
+/// Rejects any field value that would be lost converting into its enum.
+fn check_known_values(builder: SteamIdBuilder) -> Result<SteamIdBuilder, ParseError> {
+    let id = builder.id;
+    let universe = ((id & mask::UNIVERSE) >> shift::UNIVERSE) as u8;
+    let account_type = ((id & mask::ACCOUNT_TYPE) >> shift::ACCOUNT_TYPE) as u8;
+    let instance = ((id & mask::INSTANCE) >> shift::INSTANCE) as u32;
+    (u8::from(Universe::from(universe)) == universe)
+        .then_some(())
+        .ok_or(ParseError::Invalid(Field::Universe))?;
+    (u8::from(AccountType::from(account_type)) == account_type)
+        .then_some(())
+        .ok_or(ParseError::Invalid(Field::AccountType))?;
+    Instance::try_from_raw(instance)?;
+    Ok(builder)
+}
+
 // Ugly parsing code since we're not using Regex.
 
 #[inline]
@@ -215,24 +620,54 @@ fn parse_from_steamid64(s: &str) -> Result<SteamIdBuilder, ParseError> {
         id: s
             .parse::<u64>()
             .map_err(|_| ParseError::Invalid(Field::SteamId64))?,
+        truncated: 0,
     })
 }
 
-fn parse_from_steamid2(s: &str) -> Result<SteamIdBuilder, ParseError> {
+/// Parses a Steam Community profile or group URL, ignoring any path or query
+/// after the id, e.g. `/games/?tab=all`.
+fn parse_from_url(s: &str, mode: Mode) -> Result<SteamIdBuilder, ParseError> {
+    let url = s.trim();
+    let url = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    let path = url
+        .strip_prefix(COMMUNITY_HOST)
+        .ok_or(ParseError::UnknownFormat)?;
+    // Only the id segment matters, not any path or query after it.
+    fn segment(id: &str) -> &str {
+        id.split(['/', '?']).next().unwrap_or(id)
+    }
+    if let Some(id) = path.strip_prefix(PROFILE_PATH) {
+        let id = segment(id);
+        (!id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()))
+            .then_some(())
+            .ok_or(ParseError::Invalid(Field::SteamId64))?;
+        return parse_from_steamid64(id);
+    }
+    let id = path
+        .strip_prefix(GROUP_PATH)
+        .ok_or(ParseError::UnknownFormat)?;
+    // Groups are linked by either their SteamId3 or SteamId64.
+    parse(segment(id), mode)
+}
+
+fn parse_from_steamid2(s: &str, mode: Mode) -> Result<SteamIdBuilder, ParseError> {
     use ParseError::*;
 
-    s.starts_with("STEAM_").then(|| ()).ok_or(UnknownFormat)?;
+    s.starts_with("STEAM_").then_some(()).ok_or(UnknownFormat)?;
     let steam2 = s.get(6..).ok_or(UnknownFormat)?;
     let mut fields = steam2.split(':');
+    let universe = parse_universe(fields.next().ok_or(TooShort)?, mode)?;
     let steamid = SteamIdBuilder::new()
-        .universe(
-            u8::from_str(fields.next().ok_or(TooShort)?)
-                .map_err(|_| Invalid(Field::Universe))?
-                // Interpret 'Unspecified' universe as 'Public' to
-                // comply with Valve's implementation of steamID in
-                // legacy Source/GoldSrc engine games.
-                .max(1),
-        )
+        .universe(match mode {
+            Mode::Strict => universe,
+            // Interpret 'Unspecified' universe as 'Public' to
+            // comply with Valve's implementation of steamID in
+            // legacy Source/GoldSrc engine games.
+            _ => universe.max(1),
+        })
         .authentication_server(
             fields
                 .next()
@@ -241,7 +676,7 @@ fn parse_from_steamid2(s: &str) -> Result<SteamIdBuilder, ParseError> {
                 .map_err(|_| Invalid(Field::AuthServer))
                 .and_then(|v: u64| {
                     // Catch values here that would be clipped otherwise.
-                    (v < 2).then(|| v).ok_or(Invalid(Field::AuthServer))
+                    (v < 2).then_some(v).ok_or(Invalid(Field::AuthServer))
                 })?,
         )
         .account_number(
@@ -253,7 +688,7 @@ fn parse_from_steamid2(s: &str) -> Result<SteamIdBuilder, ParseError> {
                 .and_then(|v: u64| {
                     // Account Number is only 31 bits or less.
                     (v < 2u64.pow(31))
-                        .then(|| v)
+                        .then_some(v)
                         .ok_or(Invalid(Field::AccountNumber))
                 })?,
         )
@@ -265,59 +700,192 @@ fn parse_from_steamid2(s: &str) -> Result<SteamIdBuilder, ParseError> {
     Ok(steamid)
 }
 
-fn parse_from_steamid3(s: &str) -> Result<SteamIdBuilder, ParseError> {
+fn parse_from_steamid3(s: &str, mode: Mode) -> Result<SteamIdBuilder, ParseError> {
     use ParseError::*;
 
     let inv_an = Invalid(Field::AccountNumber);
     let inv_at = Invalid(Field::AccountType);
     // SteamId3 must be terminated with a bracket.
     (s.chars().last().ok_or(TooShort)? == ']')
-        .then(|| ())
+        .then_some(())
         .ok_or(UnknownFormat)?;
     let steam3 = s.get(1..s.len() - 1).ok_or(UnknownFormat)?;
     let mut fields = steam3.split(':');
     let acc_type = fields.next().ok_or(TooShort)?;
     let universe = fields.next().ok_or(TooShort)?;
     let auth_server = fields.next().ok_or(TooShort)?;
-    // A valid SteamId3 input should be empty after consuming all 3 fields –
+    let fourth = fields.next();
+    // Game servers may have their instance appended, `[G:1:Z:I]`.
+    let game_server = matches!(acc_type, "G" | "A");
+    // Some tools split the auth bit and account number into separate fields,
+    // `[U:1:Y:Z]`, only tolerate this when asked to.
+    (fourth.is_none() || game_server || mode == Mode::Lenient)
+        .then_some(())
+        .ok_or(UnknownFormat)?;
+    // A valid SteamId3 input should be empty after consuming all fields –
     // if it isn't, it was formatted incorrectly.
     fields.next().map_or(Ok(()), |_| Err(UnknownFormat))?;
-    let steamid = SteamIdBuilder::new()
-        .universe(u8::from_str(universe).map_err(|_| Invalid(Field::Universe))?)
-        .authentication_server(
-            auth_server
-                .parse()
-                .map_err(|_| ParseError::Invalid(Field::AuthServer))
-                .map(|v: u64| v & mask::AUTH_SERVER)?,
-        )
-        .account_number(
-            auth_server
+    let universe = parse_universe(universe, mode)?;
+    let parse_account_id = |v: &str| {
+        v.parse::<u64>()
+            .map_err(|_| Invalid(Field::AuthServer))
+            .and_then(|v: u64| {
+                // Account Number is only 31 bits or less.
+                (v <= u32::MAX as u64).then_some(v).ok_or(inv_an)
+            })
+    };
+    let (account_id, instance) = match fourth {
+        Some(instance) if game_server => {
+            let account_id = parse_account_id(auth_server)?;
+            let instance = instance
                 .parse::<u64>()
-                .map_err(|_| inv_an)
-                .and_then(|v: u64| {
-                    // Account Number is only 31 bits or less.
-                    (v <= u32::MAX as u64).then(|| v).ok_or(inv_an)
-                })?
-                >> shift::ACCOUNT_NUMBER,
-        )
+                .map_err(|_| Invalid(Field::Instance))
+                .and_then(|v| {
+                    (v <= mask::INSTANCE >> shift::INSTANCE)
+                        .then_some(v)
+                        .ok_or(Invalid(Field::Instance))
+                })?;
+            (account_id, Some(instance))
+        }
+        Some(account_number) => {
+            let auth_server = auth_server
+                .parse()
+                .map_err(|_| Invalid(Field::AuthServer))
+                .and_then(|v: u64| (v < 2).then_some(v).ok_or(Invalid(Field::AuthServer)))?;
+            let account_number =
+                account_number
+                    .parse()
+                    .map_err(|_| inv_an)
+                    .and_then(|v: u64| {
+                        // Account Number is only 31 bits or less.
+                        (v < 2u64.pow(31)).then_some(v).ok_or(inv_an)
+                    })?;
+            (
+                (account_number << shift::ACCOUNT_NUMBER) | auth_server,
+                None,
+            )
+        }
+        None => (parse_account_id(auth_server)?, None),
+    };
+    let steamid = SteamIdBuilder::new()
+        .universe(universe)
+        .authentication_server(account_id & mask::AUTH_SERVER)
+        .account_number(account_id >> shift::ACCOUNT_NUMBER)
         .account_type(
             char::from_str(acc_type)
                 .map_err(|_| inv_at)
                 .and_then(|v| {
                     // SteamId3 should only accept alphabet characters.
-                    v.is_ascii_alphabetic().then(|| v).ok_or(inv_at)
+                    v.is_ascii_alphabetic().then_some(v).ok_or(inv_at)
                 })
                 .and_then(|v| {
                     // Don't accept 'invalid' account types when parsing,
                     // unless explicitly passed.
                     (char::from(AccountType::from(v)) == v)
-                        .then(|| v)
+                        .then_some(v)
                         .ok_or(inv_at)
                 })?,
         );
+    // Set directly, as not every instance value has an `Instance`.
+    let steamid = match instance {
+        Some(v) => SteamIdBuilder {
+            id: replace_bits(steamid.id, mask::INSTANCE, v << shift::INSTANCE),
+            ..steamid
+        },
+        None => steamid,
+    };
     Ok(steamid)
 }
 
+/// The string formats a SteamId can be parsed from, see [detect_format].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SteamFormat {
+    /// e.g. `76561197990953833`
+    SteamId64,
+    /// e.g. `STEAM_1:1:15344052`
+    SteamId2,
+    /// A SteamId2 with a universe of `0`, e.g. `STEAM_0:1:15344052`
+    SteamId2Legacy,
+    /// e.g. `[U:1:30688105]`
+    SteamId3,
+    /// e.g. `https://steamcommunity.com/profiles/76561197990953833`
+    Url,
+}
+
+/// Guesses which [SteamFormat] `s` is in, using the same first byte dispatch
+/// as [SteamId]'s [FromStr], without fully parsing it.
+///
+/// A [Some] result doesn't mean `s` will parse, only that it's shaped like
+/// that format.
+/// # Example
+/// ```
+/// use steamid::{detect_format, SteamFormat};
+///
+/// assert_eq!(detect_format("STEAM_0:1:15344052"), Some(SteamFormat::SteamId2Legacy));
+/// assert_eq!(detect_format("[U:1:30688105]"), Some(SteamFormat::SteamId3));
+/// assert_eq!(detect_format("gabe"), None);
+/// ```
+pub fn detect_format(s: &str) -> Option<SteamFormat> {
+    let s = s.trim();
+    match s.as_bytes().first()? {
+        b'0'..=b'9' => Some(SteamFormat::SteamId64),
+        b'S' if s.starts_with("STEAM_0:") => Some(SteamFormat::SteamId2Legacy),
+        b'S' if s.starts_with("STEAM_") => Some(SteamFormat::SteamId2),
+        b'[' => Some(SteamFormat::SteamId3),
+        b'h' if s.starts_with("http://") || s.starts_with("https://") => Some(SteamFormat::Url),
+        _ => None,
+    }
+}
+
+/// Parses every token in `input` separated by whitespace or commas, in order,
+/// skipping empty tokens.
+///
+/// See [SteamId::parse_many] to also get back the token for each result.
+/// # Example
+/// ```
+/// use steamid::parse_list;
+///
+/// let ids = parse_list("76561197990953833, [U:1:30688105]\nnope");
+/// assert_eq!(ids.len(), 3);
+/// assert!(ids[2].is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_list(input: &str) -> Vec<Result<SteamId, ParseError>> {
+    input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .map(SteamId::from_str)
+        .collect()
+}
+
+/// Normalizes every SteamId in place, as is typically done before
+/// de-duplicating a list of ids from mixed sources.
+///
+/// All ids are moved into the [Universe::Public] universe, and individual
+/// accounts have their [Instance] reset to [Instance::Desktop], dropping any
+/// chat bits. Other account types keep their instance as-is, since it
+/// carries meaning for them.
+/// # Example
+/// ```
+/// use steamid::{normalize_all, SteamId};
+///
+/// let mut ids = [
+///     "[U:1:30688105]".parse().unwrap(),
+///     SteamId::from(148618792028881769),
+/// ];
+/// normalize_all(&mut ids);
+/// assert_eq!(ids[0], ids[1]);
+/// ```
+pub fn normalize_all(ids: &mut [SteamId]) {
+    for id in ids.iter_mut() {
+        let mut builder = SteamIdBuilder::from(*id).universe(Universe::Public);
+        if id.account_type() == AccountType::Individual {
+            builder = builder.instance(Instance::Desktop(ChatType::None));
+        }
+        *id = builder.finish();
+    }
+}
+
 /// "Read-Only" SteamId with associated methods for getting values.
 ///
 /// # Formats
@@ -352,6 +920,10 @@ fn parse_from_steamid3(s: &str) -> Result<SteamIdBuilder, ParseError> {
 /// [SteamIdBuilder] using the `SteamIdBuilder::from()` method,
 /// and use the associated functions to set desired values;
 /// then `.finish()` the builder to return a SteamId.
+/// # Ordering
+/// SteamIds are ordered by their raw SteamId64 value, not by account number,
+/// so the [Universe] and [AccountType] are compared before the account
+/// number. Use [SteamId::account_id] as a sort key to order by account.
 /// # Examples
 /// - Getting a SteamId64 directly from a u64
 /// ```
@@ -399,12 +971,27 @@ fn parse_from_steamid3(s: &str) -> Result<SteamIdBuilder, ParseError> {
 ///     .finish();
 /// assert_eq!(u64::from(player), 76561210875855721)
 /// ```
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
 pub struct SteamId {
     pub(crate) id: u64,
 }
 
 impl SteamId {
+    /// Creates a SteamId from its SteamId64 value, the same as [From], but
+    /// usable in `const` contexts.
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// const USER: SteamId = SteamId::new(76561197990953833);
+    /// const ACCOUNT_NUMBER: u32 = USER.account_number();
+    /// assert_eq!(ACCOUNT_NUMBER, 15344052)
+    /// ```
+    pub const fn new(id: u64) -> SteamId {
+        SteamId { id }
+    }
+
     /// Returns the authentication bit for this SteamId
     /// # Example
     ///
@@ -414,7 +1001,7 @@ impl SteamId {
     /// let user: SteamId = "[U:1:30688105]".parse().unwrap();
     /// assert_eq!(user.authentication_server(), 1)
     /// ```
-    pub fn authentication_server(&self) -> u32 {
+    pub const fn authentication_server(&self) -> u32 {
         (self.id & mask::AUTH_SERVER) as u32
     }
 
@@ -427,363 +1014,3058 @@ impl SteamId {
     /// let user: SteamId = "[U:1:30688105]".parse().unwrap();
     /// assert_eq!(user.account_number(), 15344052)
     /// ```
-    pub fn account_number(&self) -> u32 {
+    pub const fn account_number(&self) -> u32 {
         ((self.id & mask::ACCOUNT_NUMBER) >> shift::ACCOUNT_NUMBER) as u32
     }
 
-    /// Returns the [AccountType] for this SteamId
+    /// Returns the 32-bit "account id", the [Field::AccountNumber] and
+    /// [Field::AuthServer] packed together.
+    ///
+    /// This is what most Steam Web API endpoints call the AccountID.
     /// # Example
     ///
     /// ```
-    /// use steamid::{SteamId, AccountType};
+    /// use steamid::SteamId;
     ///
-    /// let id = SteamId::from(103582791464489035);
-    /// assert_eq!(id.account_type(), AccountType::Clan)
+    /// let user: SteamId = "[U:1:30688105]".parse().unwrap();
+    /// assert_eq!(user.account_id(), 30688105)
     /// ```
-    pub fn account_type(self) -> AccountType {
-        AccountType::from(self)
+    pub const fn account_id(&self) -> u32 {
+        (self.id & (mask::ACCOUNT_NUMBER | mask::AUTH_SERVER)) as u32
     }
 
-    /// Returns the [Instance] for this SteamId
+    /// Creates an [AccountType::Individual] SteamId from a 32-bit account id,
+    /// the inverse of [SteamId::account_id].
     ///
-    /// **Note**: Chat-typed Ids will have bits set > 4096.
+    /// Every other field uses the defaults from [SteamIdBuilder::new].
     /// # Example
+    ///
     /// ```
-    /// use steamid::{ChatType, SteamId, Instance};
+    /// use steamid::SteamId;
     ///
-    /// let id = SteamId::from(108156759836037195);
-    /// assert_eq!(id.instance(), Instance::None(ChatType::ClanChat))
+    /// let user = SteamId::from_account_id(30688105);
+    /// assert_eq!(u64::from(user), 76561197990953833)
     /// ```
-    pub fn instance(self) -> Instance {
-        Instance::from(self)
+    pub fn from_account_id(id: u32) -> SteamId {
+        SteamIdBuilder::new()
+            .account_number((id >> 1) as u64)
+            .authentication_server((id & 1) as u64)
+            .finish()
     }
 
-    /// Returns the [Universe] for this SteamId
+    /// Parses a CS:GO / CS2 friend code, such as `SUCVS-FADA`.
+    ///
+    /// Friend codes only cover the account id, so every other field uses
+    /// the defaults from [SteamIdBuilder::new], like [SteamId::from_account_id].
     /// # Example
+    ///
     /// ```
-    /// use steamid::{SteamId, Universe};
+    /// use steamid::{IdFormat, SteamId};
     ///
-    /// let id: SteamId = "[U:1:30688105]".parse().unwrap();
-    /// assert_eq!(id.universe(), Universe::Public)
+    /// let user = SteamId::from_friend_code("SUCVS-FADA").unwrap();
+    /// assert_eq!(u64::from(user), 76561197960287930);
+    /// assert_eq!(IdFormat::FriendCode(user).to_string(), "SUCVS-FADA");
     /// ```
-    pub fn universe(self) -> Universe {
-        Universe::from(self)
+    pub fn from_friend_code(code: &str) -> Result<SteamId, ParseError> {
+        Ok(SteamId::from_account_id(parse_friend_code(code)?))
     }
-}
 
-// Let users cast directly from a u64 to a SteamId if they want.
-impl From<u64> for SteamId {
-    fn from(id: u64) -> Self {
-        SteamId { id }
+    /// Returns whether `code` is the friend code of this SteamId's account
+    /// id, `false` if it can't be decoded.
+    ///
+    /// Only the account id is compared, as that's all a friend code holds.
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from(76561197960287930);
+    /// assert!(user.matches_friend_code("SUCVS-FADA"));
+    /// assert!(!user.matches_friend_code("SUCVS-FADB"));
+    /// ```
+    pub fn matches_friend_code(&self, code: &str) -> bool {
+        parse_friend_code(code).is_ok_and(|account_id| account_id == self.account_id())
     }
-}
 
-impl From<SteamId> for u64 {
-    fn from(steamid: SteamId) -> Self {
-        steamid.id
+    /// Parses a Steam invite code, such as `cv-dgb`, optionally as a full
+    /// `https://s.team/p/` link.
+    ///
+    /// Dashes are ignored. Invite codes only cover the account id, so every
+    /// other field uses the defaults from [SteamIdBuilder::new], like
+    /// [SteamId::from_account_id].
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::{IdFormat, SteamId};
+    ///
+    /// let user = SteamId::from_invite_code("https://s.team/p/cv-dgb").unwrap();
+    /// assert_eq!(user.account_id(), 0x1e240);
+    /// assert_eq!(IdFormat::InviteCode(user).to_string(), "cv-dgb");
+    /// ```
+    pub fn from_invite_code(code: &str) -> Result<SteamId, ParseError> {
+        Ok(SteamId::from_account_id(parse_invite_code(code)?))
     }
-}
 
-impl FromStr for SteamId {
-    type Err = ParseError;
+    /// Returns the **Z** field of this SteamId's SteamId2 `STEAM_X:Y:Z`
+    /// representation.
+    ///
+    /// This is the same value as [SteamId::account_number], not the combined
+    /// account id that SteamId3 uses, see [SteamId::steam3_z].
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user: SteamId = "STEAM_1:1:15344052".parse().unwrap();
+    /// assert_eq!(user.steam2_z(), 15344052)
+    /// ```
+    pub fn steam2_z(&self) -> u32 {
+        self.account_number()
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(SteamIdBuilder::from_str(s)?.finish())
+    /// Returns the **Z** field of this SteamId's SteamId3 `[X:Y:Z]`
+    /// representation.
+    ///
+    /// This is the same value as [SteamId::account_id].
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user: SteamId = "[U:1:30688105]".parse().unwrap();
+    /// assert_eq!(user.steam3_z(), 30688105)
+    /// ```
+    pub fn steam3_z(&self) -> u32 {
+        self.account_id()
     }
-}
 
-/// Used to wrap a [SteamId] to specify output formatting, since [SteamId] does not implement [Display].
-///
-/// # Examples #
-///
-/// ```
-/// use steamid::{IdFormat, SteamIdBuilder, SteamId};
-///
-/// let user = SteamIdBuilder::new()
-///     .account_number(15344052)
-///     .authentication_server(1)
-///     .account_type('U')
-///     .finish();
-///
-/// assert_eq!(format!("{}",  IdFormat::SteamId64(user)), "76561197990953833");
-/// assert_eq!(format!("{}",  IdFormat::SteamId2(user)), "STEAM_1:1:15344052");
-/// assert_eq!(format!("{}",  IdFormat::SteamId3(user)), "[U:1:30688105]");
-/// assert_eq!(format!("{}",  IdFormat::Url(SteamId::from(103582791464489035))), "http://steamcommunity.com/gid/[g:1:34967627]");
-///```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum IdFormat {
-    /// The full 64-bit "steamID64"
+    /// Returns the bits of the SteamId64 that belong to `field`, left in
+    /// place rather than shifted down.
     ///
-    /// Example: `76561197960265731`
-    SteamId64(SteamId),
-    /// Older "steamID" format used commonly.
+    /// [Field::SteamId64] returns the whole value.
+    /// # Example
     ///
-    /// Example: `STEAM_1:1:15344052`
-    SteamId2(SteamId),
-    /// This is different from normal `SteamId2` in that the universe is always
-    /// shown as `0`.
+    /// ```
+    /// use steamid::SteamId;
+    /// use steamid::errors::Field;
     ///
-    /// ## Example ##
-    /// For SteamId3 `[U:1:3]`
-    /// - SteamId2  = `STEAM_1:1:1`
-    /// - SteamId2Legacy = `STEAM_0:1:1`
-    SteamId2Legacy(SteamId),
-    /// Modern preferred standard.
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(user.field_bits(Field::Universe), 1 << 56)
+    /// ```
+    pub fn field_bits(&self, field: Field) -> u64 {
+        self.id & field_mask(field)
+    }
+
+    /// Formats this SteamId as both [IdFormat::SteamId2] and
+    /// [IdFormat::SteamId3], and confirms the SteamId3 `Z` agrees with the
+    /// SteamId2 auth server bit and account number, `Z = Z2 * 2 + Y2`.
     ///
-    /// Example: `[U:1:30688105]`
-    SteamId3(SteamId),
-    /// Web address for the SteamId.
+    /// This checks the two formatters against each other rather than the
+    /// SteamId's bits, which can't disagree with themselves.
+    /// # Example
     ///
-    /// ## Example ##
-    /// `http://steamcommunity.com/profiles/76561197990953833`
+    /// ```
+    /// use steamid::SteamId;
     ///
-    /// `http://steamcommunity.com/gid/[g:1:34967627]`
-    Url(SteamId),
-}
+    /// let user: SteamId = "[U:1:30688105]".parse().unwrap();
+    /// assert!(user.verify_packing())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn verify_packing(&self) -> bool {
+        packing_matches(
+            &IdFormat::SteamId2(*self).to_string(),
+            &IdFormat::SteamId3(*self).to_string(),
+        )
+    }
+
+    /// Returns the [AccountType] for this SteamId
+    /// # Example
+    ///
+    /// ```
+    /// use steamid::{SteamId, AccountType};
+    ///
+    /// let id = SteamId::from(103582791464489035);
+    /// assert_eq!(id.account_type(), AccountType::Clan)
+    /// ```
+    pub fn account_type(self) -> AccountType {
+        AccountType::from(self)
+    }
+
+    /// Returns the [Instance] for this SteamId
+    ///
+    /// **Note**: Chat-typed Ids will have bits set > 4096.
+    /// # Example
+    /// ```
+    /// use steamid::{ChatType, SteamId, Instance};
+    ///
+    /// let id = SteamId::from(108156759836037195);
+    /// assert_eq!(id.instance(), Instance::None(ChatType::ClanChat))
+    /// ```
+    pub fn instance(self) -> Instance {
+        Instance::from(self)
+    }
+
+    /// Returns the [Universe] for this SteamId
+    /// # Example
+    /// ```
+    /// use steamid::{SteamId, Universe};
+    ///
+    /// let id: SteamId = "[U:1:30688105]".parse().unwrap();
+    /// assert_eq!(id.universe(), Universe::Public)
+    /// ```
+    pub fn universe(self) -> Universe {
+        Universe::from(self)
+    }
+
+    /// Returns the SteamId3 type letter of the [AccountType], e.g. `U` or
+    /// `g`, the same as `char::from(id.account_type())`.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// assert_eq!(SteamId::from(103582791464489035).account_type_char(), 'g');
+    /// ```
+    pub fn account_type_char(&self) -> char {
+        char::from(self.account_type())
+    }
+
+    /// Returns the lowercase name of the [Universe], see [Universe::name].
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// assert_eq!(SteamId::from(76561197990953833).universe_name(), "public");
+    /// ```
+    pub fn universe_name(&self) -> &'static str {
+        self.universe().name()
+    }
+
+    /// Returns the raw [Field::Universe] value, for `const` contexts where
+    /// [SteamId::universe] can't be used.
+    pub const fn universe_raw(&self) -> u8 {
+        ((self.id & mask::UNIVERSE) >> shift::UNIVERSE) as u8
+    }
+
+    /// Returns the raw [Field::AccountType] value, for `const` contexts where
+    /// [SteamId::account_type] can't be used.
+    pub const fn account_type_raw(&self) -> u8 {
+        ((self.id & mask::ACCOUNT_TYPE) >> shift::ACCOUNT_TYPE) as u8
+    }
+
+    /// Returns the raw 20-bit [Field::Instance] value, including the
+    /// [ChatType] bits, for `const` contexts where [SteamId::instance] can't
+    /// be used.
+    pub const fn instance_raw(&self) -> u32 {
+        ((self.id & mask::INSTANCE) >> shift::INSTANCE) as u32
+    }
+
+    /// Parses a SteamId like [FromStr], but tolerates some non-standard forms
+    /// seen in the wild.
+    ///
+    /// Any number of surrounding `"` or `\"` pairs are removed first, such
+    /// as from `"\"76561197990953833\""`, for ids that were quoted more than
+    /// once. Nothing else is un-escaped.
+    ///
+    /// For SteamId3, a fourth field is accepted as `[X:Y:A:Z]`, with **A**
+    /// being the [Field::AuthServer] and **Z** the [Field::AccountNumber]
+    /// written separately, as in SteamId2.
+    ///
+    /// This shape is ambiguous with the `[X:Y:Z:I]` form used to append the
+    /// [Instance] of game servers. It's resolved by the account type, `G` and
+    /// `A` ids always read the fourth field as their instance, while all
+    /// other types read it as an account number, so **A** must be `0` or `1`.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let split = SteamId::from_str_lenient("[U:1:1:15344052]").unwrap();
+    /// let packed: SteamId = "[U:1:30688105]".parse().unwrap();
+    /// assert_eq!(split, packed);
+    /// ```
+    pub fn from_str_lenient(s: &str) -> Result<SteamId, ParseError> {
+        Ok(parse(s, Mode::Lenient)?.finish())
+    }
+
+    /// Parses every whitespace separated token in `input`, in order, yielding
+    /// each token alongside its result so failures can be reported.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let input = "76561197990953833\n[U:1:30688105] nope";
+    /// let results: Vec<_> = SteamId::parse_many(input).collect();
+    /// assert_eq!(results.len(), 3);
+    /// assert_eq!(results[2].0, "nope");
+    /// assert!(results[2].1.is_err());
+    /// ```
+    pub fn parse_many(input: &str) -> impl Iterator<Item = (&str, Result<SteamId, ParseError>)> {
+        input
+            .split_ascii_whitespace()
+            .map(|token| (token, SteamId::from_str(token)))
+    }
+
+    /// Parses a SteamId like [FromStr], but rejects anything that would
+    /// otherwise be silently changed to fit.
+    ///
+    /// On top of the default parsing, strict mode:
+    /// - Rejects universes [Universe] doesn't know of, rather than treating
+    ///   them as [Universe::Unspecified].
+    /// - Keeps a SteamId2 universe of `0` as [Universe::Unspecified], rather
+    ///   than treating it as [Universe::Public].
+    /// - Rejects SteamId64 values whose account type or instance isn't a
+    ///   known [AccountType] or [Instance].
+    ///
+    /// # Example
+    /// ```
+    /// use steamid::{SteamId, Universe};
+    ///
+    /// assert!("STEAM_9:1:1".parse::<SteamId>().is_ok());
+    /// assert!(SteamId::parse_strict("STEAM_9:1:1").is_err());
+    ///
+    /// let legacy = SteamId::parse_strict("STEAM_0:1:1").unwrap();
+    /// assert_eq!(legacy.universe(), Universe::Unspecified);
+    /// ```
+    pub fn parse_strict(s: &str) -> Result<SteamId, ParseError> {
+        Ok(parse(s, Mode::Strict)?.finish())
+    }
+
+    /// Parses a SteamId from its raw 64-bit value written as exactly 16
+    /// hexadecimal characters, most significant byte first, with no `0x` prefix.
+    ///
+    /// This is how some binary dumps print ids, e.g. `0110000101d44369`.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from_hex_bytes("0110000101d44369").unwrap();
+    /// assert_eq!(u64::from(user), 76561197990953833)
+    /// ```
+    pub fn from_hex_bytes(s: &str) -> Result<SteamId, ParseError> {
+        (s.len() == 16)
+            .then_some(())
+            .ok_or(ParseError::UnknownFormat)?;
+        // from_str_radix tolerates a leading sign, so check digits ourselves.
+        s.bytes()
+            .all(|b| b.is_ascii_hexdigit())
+            .then_some(())
+            .ok_or(ParseError::Invalid(Field::SteamId64))?;
+        u64::from_str_radix(s, 16)
+            .map(SteamId::from)
+            .map_err(|_| ParseError::Invalid(Field::SteamId64))
+    }
+
+    /// Parses the account id token CS2's console prints after a `#`, such as
+    /// `#30688105`, into an individual SteamId.
+    ///
+    /// The token is the 32-bit account id, the same value as the last field in
+    /// a SteamId3, the leading `#` is optional.
+    /// # Example
+    /// ```
+    /// use steamid::{SteamId, IdFormat};
+    ///
+    /// let user = SteamId::from_cs2_status_token("#30688105").unwrap();
+    /// assert_eq!(IdFormat::SteamId3(user).to_string(), "[U:1:30688105]")
+    /// ```
+    pub fn from_cs2_status_token(token: &str) -> Result<SteamId, ParseError> {
+        let token = token.trim();
+        let account_id = token.strip_prefix('#').unwrap_or(token);
+        (!account_id.is_empty())
+            .then_some(())
+            .ok_or(ParseError::Empty)?;
+        // u32::from_str tolerates a leading sign, so check digits ourselves.
+        let account_id = account_id
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+            .then(|| u32::from_str(account_id).ok())
+            .flatten()
+            .ok_or(ParseError::Invalid(Field::AccountNumber))?;
+        Ok(SteamId::from_account_id(account_id))
+    }
+
+    /// Packs raw field values directly into a SteamId, without going through
+    /// the field enums, so values they can't represent are kept as-is.
+    ///
+    /// `accountid` is the 32-bit [Field::AccountNumber] and [Field::AuthServer]
+    /// packed together, as in [SteamId::steam3_z]. Values wider than their
+    /// field are truncated.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from_raw_fields(1, 1, 1, 30688105);
+    /// assert_eq!(u64::from(user), 76561197990953833);
+    /// ```
+    pub fn from_raw_fields(
+        universe: u8,
+        account_type: u8,
+        instance: u32,
+        accountid: u32,
+    ) -> SteamId {
+        let id = ((universe as u64) << shift::UNIVERSE) & mask::UNIVERSE
+            | ((account_type as u64) << shift::ACCOUNT_TYPE) & mask::ACCOUNT_TYPE
+            | ((instance as u64) << shift::INSTANCE) & mask::INSTANCE
+            | (accountid as u64) & (mask::ACCOUNT_NUMBER | mask::AUTH_SERVER);
+        SteamId { id }
+    }
+
+    /// Creates a SteamId from the `fixed64` SteamId field of a Steam protobuf
+    /// message, such as `CMsgProtoBufHeader.steamid`.
+    ///
+    /// The field already holds the packed SteamId64, so this is the same as
+    /// [SteamId::from], named for readability in protobuf handling code.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from_proto(76561197990953833);
+    /// assert_eq!(user.to_proto(), 76561197990953833);
+    /// ```
+    pub fn from_proto(value: u64) -> SteamId {
+        SteamId::from(value)
+    }
+
+    /// Returns the value to store in the `fixed64` SteamId field of a Steam
+    /// protobuf message, see [SteamId::from_proto].
+    pub fn to_proto(&self) -> u64 {
+        self.id
+    }
+
+    /// Encodes the SteamId into the crate's 8-byte binary format, the
+    /// SteamId64 in big-endian byte order.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(user.encode(), [0x01, 0x10, 0x00, 0x01, 0x01, 0xd4, 0x43, 0x69]);
+    /// ```
+    pub fn encode(&self) -> [u8; 8] {
+        self.id.to_be_bytes()
+    }
+
+    /// Decodes a SteamId from the binary format produced by [SteamId::encode].
+    ///
+    /// `bytes` must be exactly 8 bytes long.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::decode(&[0x01, 0x10, 0x00, 0x01, 0x01, 0xd4, 0x43, 0x69]).unwrap();
+    /// assert_eq!(u64::from(user), 76561197990953833);
+    /// ```
+    pub fn decode(bytes: &[u8]) -> Result<SteamId, ParseError> {
+        match bytes.len() {
+            0 => Err(ParseError::Empty),
+            1..=7 => Err(ParseError::TooShort),
+            8 => {
+                let mut raw = [0; 8];
+                raw.copy_from_slice(bytes);
+                Ok(SteamId::from(u64::from_be_bytes(raw)))
+            }
+            _ => Err(ParseError::UnknownFormat),
+        }
+    }
+
+    /// Splits the SteamId64 into two 32-bit words, returned as `(low, high)`.
+    ///
+    /// - `low` holds the [Field::AuthServer] and [Field::AccountNumber], the
+    ///   same value as [SteamId::steam3_z].
+    /// - `high` holds the [Instance], [AccountType] and [Universe].
+    ///
+    /// The words are split numerically, so this is independent of the
+    /// platform's endianness.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(user.to_u32_pair(), (30688105, 17825793));
+    /// ```
+    pub fn to_u32_pair(&self) -> (u32, u32) {
+        (self.id as u32, (self.id >> 32) as u32)
+    }
+
+    /// Joins two 32-bit words into a SteamId, the inverse of
+    /// [SteamId::to_u32_pair].
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from_u32_pair(30688105, 17825793);
+    /// assert_eq!(u64::from(user), 76561197990953833);
+    /// ```
+    pub fn from_u32_pair(low: u32, high: u32) -> SteamId {
+        SteamId::from(((high as u64) << 32) | low as u64)
+    }
+
+    /// Parses the `accountid:instance` pair notation, e.g. `30688105:1`,
+    /// into an individual SteamId.
+    ///
+    /// The account id is the same value as [SteamId::steam3_z], and the
+    /// instance must fit in its 20 bits.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from_accountid_instance("30688105:1").unwrap();
+    /// assert_eq!(u64::from(user), 76561197990953833);
+    /// ```
+    pub fn from_accountid_instance(s: &str) -> Result<SteamId, ParseError> {
+        use ParseError::*;
+
+        let s = s.trim();
+        (!s.is_empty()).then_some(()).ok_or(Empty)?;
+        let (account_id, instance) = s.split_once(':').ok_or(TooShort)?;
+        let parse_digits = |v: &str, field| {
+            // from_str tolerates a leading sign, so check digits ourselves.
+            v.bytes()
+                .all(|b| b.is_ascii_digit())
+                .then(|| u32::from_str(v).ok())
+                .flatten()
+                .ok_or(Invalid(field))
+        };
+        let account_id = parse_digits(account_id, Field::AccountNumber)?;
+        let instance = parse_digits(instance, Field::Instance)
+            .and_then(|v| (v < 1 << 20).then_some(v).ok_or(Invalid(Field::Instance)))?;
+        // Packed raw since not every instance value has an `Instance`.
+        Ok(SteamId::from_raw_fields(
+            Universe::Public.into(),
+            AccountType::Individual.into(),
+            instance,
+            account_id,
+        ))
+    }
+
+    /// Formats the SteamId in the `accountid:instance` pair notation.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(user.to_accountid_instance(), "30688105:1");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_accountid_instance(&self) -> String {
+        format!(
+            "{}:{}",
+            self.steam3_z(),
+            (self.id & mask::INSTANCE) >> shift::INSTANCE
+        )
+    }
+
+    /// Consumes the SteamId and returns the underlying SteamId64 value.
+    ///
+    /// Equivalent to `u64::from(steamid)`.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user: SteamId = "[U:1:30688105]".parse().unwrap();
+    /// assert_eq!(user.into_inner(), 76561197990953833)
+    /// ```
+    pub const fn into_inner(self) -> u64 {
+        self.id
+    }
+
+    /// Returns the SteamId64 as a decimal string.
+    ///
+    /// Unlike `to_string()` this doesn't depend on the [Display]
+    /// implementation, so it will always be the SteamId64.
+    #[cfg(feature = "alloc")]
+    pub fn to_steam64_string(&self) -> String {
+        self.id.to_string()
+    }
+
+    /// Returns the SteamId64 zero-padded to 20 digits, the width of
+    /// [u64::MAX], so sorting these strings matches sorting the SteamIds.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// assert_eq!(SteamId::from(1).sort_key(), "00000000000000000001");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn sort_key(&self) -> String {
+        format!("{:020}", self.id)
+    }
+
+    /// Returns a filesystem-safe name for this SteamId, its SteamId64.
+    ///
+    /// See [SteamId::to_filename_fmt] for other formats.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(user.to_filename(), "76561197990953833");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_filename(&self) -> String {
+        self.to_steam64_string()
+    }
+
+    /// Returns this SteamId formatted with `fmt`, made safe to use as a
+    /// filename.
+    ///
+    /// Each `:`, `[`, `]`, `/`, and `?` is replaced with `_`, no other
+    /// characters are changed.
+    /// # Example
+    /// ```
+    /// use steamid::{OutputFormat, SteamId};
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(user.to_filename_fmt(OutputFormat::SteamId3), "_U_1_30688105_");
+    /// assert_eq!(user.to_filename_fmt(OutputFormat::SteamId2), "STEAM_1_1_15344052");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_filename_fmt(&self, fmt: OutputFormat) -> String {
+        IdFormat::new(fmt, *self)
+            .to_string()
+            .replace([':', '[', ']', '/', '?'], "_")
+    }
+
+    /// Returns a short handle for mentions, the SteamId3 type letter followed
+    /// by the [Field::AccountNumber], e.g. `U15344052`.
+    ///
+    /// **Note**: Like SteamId2, the [Field::AuthServer] isn't part of the
+    /// handle, see [SteamId::from_handle].
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(format!("@{}", user.handle()), "@U15344052");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn handle(&self) -> String {
+        format!(
+            "{}{}",
+            char::from(self.account_type()),
+            self.account_number()
+        )
+    }
+
+    /// Parses a handle made by [SteamId::handle], with or without a leading
+    /// `@`.
+    ///
+    /// Like SteamId3, the [Universe] is [Universe::Public] and the [Instance]
+    /// follows from the account type. The [Field::AuthServer] isn't stored in
+    /// a handle, so it's always `0`.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from_handle("@U15344052").unwrap();
+    /// assert_eq!(user.account_number(), 15344052);
+    /// assert_eq!(user.handle(), "U15344052");
+    /// ```
+    pub fn from_handle(s: &str) -> Result<SteamId, ParseError> {
+        let s = s.strip_prefix('@').unwrap_or(s);
+        let split = s.char_indices().nth(1).map_or(s.len(), |(i, _)| i);
+        let (letter, number) = s.split_at(split);
+        let account_type: AccountType = letter.parse()?;
+        (!number.is_empty())
+            .then_some(())
+            .ok_or(ParseError::TooShort)?;
+        number
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+            .then_some(())
+            .ok_or(ParseError::Invalid(Field::AccountNumber))?;
+        let account_number = number
+            .parse::<u64>()
+            .ok()
+            .filter(|&n| n <= mask::ACCOUNT_NUMBER >> shift::ACCOUNT_NUMBER)
+            .ok_or(ParseError::Invalid(Field::AccountNumber))?;
+        Ok(SteamIdBuilder::new()
+            .account_type(account_type)
+            .account_number(account_number)
+            .finish())
+    }
+
+    /// Returns a one line, human readable summary of this SteamId, for logs.
+    ///
+    /// **Note**: The output is only meant for reading and may change, it
+    /// can't be parsed back into a SteamId.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(user.humanize(), "Public Individual #15344052 (Desktop)");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn humanize(&self) -> String {
+        format!(
+            "{} {} #{} ({})",
+            self.universe(),
+            self.account_type().name(),
+            self.account_number(),
+            self.instance()
+        )
+    }
+
+    /// Returns a labeled, multi-line breakdown of this SteamId, for CLI
+    /// output.
+    ///
+    /// Lists every [IdFormat] with a fixed layout, followed by each decoded
+    /// field. Like [SteamId::humanize], the output is only meant for reading
+    /// and may change.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// let explained = user.explain();
+    /// assert!(explained.contains("[U:1:30688105]"));
+    /// println!("{explained}");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn explain(&self) -> String {
+        let id = *self;
+        let account_type = self.account_type();
+        let universe = self.universe();
+        let lines = [
+            ("steamID64", IdFormat::SteamId64(id).to_string()),
+            ("steamID", IdFormat::SteamId2(id).to_string()),
+            ("steamID (legacy)", IdFormat::SteamId2Legacy(id).to_string()),
+            ("steamID3", IdFormat::SteamId3(id).to_string()),
+            ("URL", IdFormat::Url(id).to_string()),
+            ("Universe", format!("{universe} ({})", u8::from(universe))),
+            (
+                "Account type",
+                format!("{account_type:?} ({})", char::from(account_type)),
+            ),
+            (
+                "Instance",
+                format!("{} ({})", self.instance(), self.instance_raw()),
+            ),
+            ("Account number", self.account_number().to_string()),
+            ("Auth server", self.authentication_server().to_string()),
+            ("Account id", self.account_id().to_string()),
+        ];
+        lines
+            .iter()
+            .map(|(label, value)| format!("{:<18}{value}", format!("{label}:")))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns whether both SteamIds are in the same [Universe].
+    ///
+    /// The raw universe bits are compared, so universes that [Universe] can't
+    /// represent are still told apart.
+    /// # Example
+    /// ```
+    /// use steamid::{SteamId, SteamIdBuilder};
+    ///
+    /// let public = SteamId::from(76561197990953833);
+    /// let beta = SteamIdBuilder::from(public).universe(2).finish();
+    /// assert!(public.same_universe(&public));
+    /// assert!(!public.same_universe(&beta));
+    /// ```
+    pub fn same_universe(&self, other: &SteamId) -> bool {
+        (self.id & mask::UNIVERSE) == (other.id & mask::UNIVERSE)
+    }
+
+    /// Returns this account in every known [Universe], from
+    /// [Universe::Unspecified] to [Universe::RC], with every other field kept.
+    /// # Example
+    /// ```
+    /// use steamid::{SteamId, Universe};
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// let all = user.across_universes();
+    /// assert_eq!(all.len(), 6);
+    /// assert_eq!(all[1], user);
+    /// assert_eq!(all[2].universe(), Universe::Beta);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn across_universes(&self) -> Vec<SteamId> {
+        (0..=u8::MAX)
+            .map_while(|v| Universe::try_from_raw(v).ok())
+            .map(|universe| SteamIdBuilder::from(*self).universe(universe).finish())
+            .collect()
+    }
+
+    /// Returns whether this SteamId passes Valve's basic sanity checks.
+    ///
+    /// Checks performed:
+    /// - The account type isn't [AccountType::Invalid], including unknown
+    ///   account types.
+    /// - The universe isn't [Universe::Unspecified], including unknown
+    ///   universes.
+    /// - [AccountType::Individual] accounts don't have an account id of `0`.
+    ///
+    /// The instance isn't checked, see [SteamIdBuilder::validate] for a
+    /// stricter, more detailed check.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// assert!(SteamId::from(76561197990953833).is_valid());
+    /// assert!(!SteamId::from(0).is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        let account_type = self.account_type();
+        account_type != AccountType::Invalid
+            && self.universe() != Universe::Unspecified
+            && (account_type != AccountType::Individual || self.account_id() != 0)
+    }
+
+    /// Returns whether this is a game server, either an
+    /// [AccountType::GameServer] or an [AccountType::AnonGameServer].
+    /// # Example
+    /// ```
+    /// use steamid::{SteamId, SteamIdBuilder};
+    ///
+    /// assert!(SteamIdBuilder::anonymous_game_server().finish().is_game_server());
+    /// assert!(!SteamId::from(76561197990953833).is_game_server());
+    /// ```
+    pub fn is_game_server(&self) -> bool {
+        matches!(
+            self.account_type(),
+            AccountType::GameServer | AccountType::AnonGameServer
+        )
+    }
+
+    /// Returns whether this SteamId's SteamId3 type letter is lowercase,
+    /// which Valve uses for clans, chats, and anonymous users (`g`, `c`, `a`).
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// assert!(SteamId::from(103582791464489035).type_letter_is_lowercase());
+    /// assert!(!SteamId::from(76561197990953833).type_letter_is_lowercase());
+    /// ```
+    pub fn type_letter_is_lowercase(&self) -> bool {
+        self.account_type().steam3_letter().is_ascii_lowercase()
+    }
+
+    /// Returns whether this is one of the special accounts listed in
+    /// [well_known](crate::well_known).
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// assert!(SteamId::from(76561197960287930).is_well_known());
+    /// assert!(!SteamId::from(76561197990953833).is_well_known());
+    /// ```
+    pub fn is_well_known(&self) -> bool {
+        crate::well_known::ALL.contains(self)
+    }
+
+    /// Returns a rough guess of when this account was registered.
+    ///
+    /// **Note**: This is only a heuristic, see [Era] for the thresholds used.
+    /// # Example
+    /// ```
+    /// use steamid::{Era, SteamId};
+    ///
+    /// let user: SteamId = "[U:1:30688105]".parse().unwrap();
+    /// assert_eq!(user.approx_era(), Era::Early)
+    /// ```
+    pub fn approx_era(&self) -> Era {
+        Era::from(*self)
+    }
+
+    /// Returns a stable seed for generating fallback art, such as an
+    /// identicon, for accounts without an avatar.
+    ///
+    /// This is the 64-bit FNV-1a hash of the [SteamId::steam3_z] account id
+    /// as hexadecimal, so it stays the same across instances and universes.
+    /// It has nothing to do with Steam's own avatar hashes.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(user.avatar_seed(), "f36623c60b0277c6")
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn avatar_seed(&self) -> String {
+        const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+        let hash = self
+            .steam3_z()
+            .to_le_bytes()
+            .iter()
+            .fold(FNV_OFFSET, |h, &b| (h ^ b as u64).wrapping_mul(FNV_PRIME));
+        format!("{hash:016x}")
+    }
+
+    /// Returns this SteamId turned into a [AccountType::Clan], with the same
+    /// side-effects as [SteamIdBuilder::account_type].
+    /// # Example
+    /// ```
+    /// use steamid::{SteamId, IdFormat};
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(
+    ///     IdFormat::Url(user.to_group()).to_string(),
+    ///     "http://steamcommunity.com/gid/[g:1:30688105]"
+    /// );
+    /// ```
+    pub fn to_group(&self) -> SteamId {
+        SteamIdBuilder::from(*self)
+            .account_type(AccountType::Clan)
+            .finish()
+    }
+
+    /// Returns the clan chat room of this group, the same account id as a
+    /// [ChatType::ClanChat] [AccountType::Chat].
+    ///
+    /// The [Instance] is reset to [Instance::None] with the clan chat flag, as
+    /// [SteamIdBuilder::account_type] does.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let group: SteamId = "[g:1:34967627]".parse().unwrap();
+    /// assert_eq!(u64::from(group.group_to_chat()), 108156759836037195);
+    /// ```
+    pub fn group_to_chat(&self) -> SteamId {
+        SteamIdBuilder::from(*self)
+            .account_type(AccountType::Chat(ChatType::ClanChat))
+            .finish()
+    }
+
+    /// Returns the group owning this clan chat room, the inverse of
+    /// [SteamId::group_to_chat].
+    ///
+    /// The [Instance] is reset to [Instance::None], dropping any channel.
+    /// # Example
+    /// ```
+    /// use steamid::{IdFormat, SteamId};
+    ///
+    /// let chat = SteamId::from(108156759836037195);
+    /// assert_eq!(IdFormat::SteamId3(chat.chat_to_group()).to_string(), "[g:1:34967627]");
+    /// ```
+    pub fn chat_to_group(&self) -> SteamId {
+        SteamIdBuilder::from(*self)
+            .account_type(AccountType::Clan)
+            .finish()
+    }
+
+    /// Separates a chat SteamId into the SteamId with its [ChatType] bits
+    /// cleared, and the [ChatType] itself.
+    ///
+    /// Non-chat accounts are returned unchanged along with [ChatType::None].
+    /// # Example
+    /// ```
+    /// use steamid::{ChatType, SteamId};
+    ///
+    /// let chat: SteamId = "[c:1:34967627]".parse().unwrap();
+    /// let (base, chat_type) = chat.split_chat();
+    /// assert_eq!(chat_type, ChatType::ClanChat);
+    /// assert_eq!(u64::from(base), 108086391091859531);
+    /// ```
+    pub fn split_chat(&self) -> (SteamId, ChatType) {
+        match self.account_type() {
+            AccountType::Chat(chat_type) => (
+                SteamId {
+                    id: self.id & !mask::CHAT_TYPE,
+                },
+                chat_type,
+            ),
+            _ => (*self, ChatType::None),
+        }
+    }
+
+    /// Returns whether both SteamIds refer to the same chat room, ignoring
+    /// any difference in their [ChatType] bits.
+    ///
+    /// Every other field, including the rest of the instance, must match.
+    /// # Example
+    /// ```
+    /// use steamid::{SteamId, SteamIdBuilder};
+    ///
+    /// let clan_chat: SteamId = "[c:1:34967627]".parse().unwrap();
+    /// let lobby = SteamIdBuilder::from(clan_chat).account_type('L').finish();
+    /// assert!(clan_chat.same_chat_room(&lobby));
+    /// assert_ne!(clan_chat, lobby);
+    /// ```
+    pub fn same_chat_room(&self, other: &SteamId) -> bool {
+        (self.id & !mask::CHAT_TYPE) == (other.id & !mask::CHAT_TYPE)
+    }
+
+    /// Returns whether both SteamIds refer to the same account, ignoring any
+    /// difference in their [Instance], such as Desktop and Web logins.
+    ///
+    /// **Note**: The [ChatType] is part of the instance, so it's ignored too.
+    /// # Example
+    /// ```
+    /// use steamid::{SteamId, SteamIdBuilder};
+    ///
+    /// let desktop = SteamId::from(76561197990953833);
+    /// let web = SteamIdBuilder::from(desktop).web().finish();
+    /// assert!(desktop.same_account(&web));
+    /// assert_ne!(desktop, web);
+    /// ```
+    pub fn same_account(&self, other: &SteamId) -> bool {
+        (self.id & !mask::INSTANCE) == (other.id & !mask::INSTANCE)
+    }
+
+    /// Returns the numeric authid used by AMX Mod X and other HL1 plugins,
+    /// which stored a `STEAM_0:Y:Z` SteamId2 as `Z * 2 + Y`.
+    ///
+    /// This is the same value as [SteamId::steam3_z], but only for
+    /// [AccountType::Individual] accounts, returning [None] otherwise.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user: SteamId = "STEAM_0:1:15344052".parse().unwrap();
+    /// assert_eq!(user.amxx_authid(), Some(30688105))
+    /// ```
+    pub fn amxx_authid(&self) -> Option<u32> {
+        (self.account_type() == AccountType::Individual).then(|| self.steam3_z())
+    }
+
+    /// Returns the chat room group id for clans and clan chats.
+    ///
+    /// Steam's newer chat backend no longer addresses group chats by their
+    /// legacy chat SteamId, instead it uses the 32-bit account id of the owning
+    /// clan. A clan and its clan chat share the same account id, so both
+    /// return the same value here.
+    ///
+    /// Returns [None] for all other account types.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let clan: SteamId = "[g:1:34967627]".parse().unwrap();
+    /// assert_eq!(clan.chat_room_group_id(), Some(34967627))
+    /// ```
+    pub fn chat_room_group_id(&self) -> Option<u32> {
+        match self.account_type() {
+            AccountType::Clan | AccountType::Chat(ChatType::ClanChat) => Some(self.account_id()),
+            _ => None,
+        }
+    }
+
+    /// Creates a [ChatType::ClanChat] SteamId for a channel within a group.
+    ///
+    /// The group's 32-bit account id becomes the account id, and the channel
+    /// is stored in the instance bits below the [ChatType].
+    ///
+    /// **Notice**: Only the lowest 12 bits of `channel` are kept.
+    /// # Example
+    /// ```
+    /// use steamid::{IdFormat, SteamId};
+    ///
+    /// let chat = SteamId::group_chat(34967627, 3);
+    /// assert_eq!(IdFormat::SteamId3(chat).to_string(), "[c:1:34967627]");
+    /// assert_eq!(chat.chat_room_group_id(), Some(34967627));
+    /// assert_eq!(chat.chat_channel(), Some(3));
+    /// ```
+    pub fn group_chat(group_accountid: u32, channel: u32) -> SteamId {
+        let id = SteamIdBuilder::new()
+            .account_type(AccountType::Chat(ChatType::ClanChat))
+            .finish()
+            .id;
+        let id = replace_bits(
+            id,
+            mask::AUTH_SERVER | mask::ACCOUNT_NUMBER,
+            group_accountid as u64,
+        );
+        let id = replace_bits(
+            id,
+            mask::INSTANCE & !mask::CHAT_TYPE,
+            (channel as u64) << shift::INSTANCE,
+        );
+        SteamId { id }
+    }
+
+    /// Returns the channel of a [ChatType::ClanChat] SteamId, as packed by
+    /// [SteamId::group_chat].
+    ///
+    /// Returns [None] for all other account types.
+    pub fn chat_channel(&self) -> Option<u32> {
+        (self.account_type() == AccountType::Chat(ChatType::ClanChat))
+            .then_some(((self.id & mask::INSTANCE & !mask::CHAT_TYPE) >> shift::INSTANCE) as u32)
+    }
+
+    /// Returns this SteamId with only the instance "place", the 12 bits below
+    /// the [ChatType], replaced by `place`.
+    ///
+    /// The account number, [ChatType], and every other field are kept. Fails
+    /// with [ParseError::Invalid] for [Field::Instance] if `place` doesn't fit.
+    /// # Example
+    /// ```
+    /// use steamid::{ChatType, SteamId};
+    ///
+    /// let chat: SteamId = "[c:1:34967627]".parse().unwrap();
+    /// let moved = chat.with_chat_place(2).unwrap();
+    /// assert_eq!(moved.chat_channel(), Some(2));
+    /// assert_eq!(moved.split_chat().1, ChatType::ClanChat);
+    /// assert!(chat.with_chat_place(0x1000).is_err());
+    /// ```
+    pub fn with_chat_place(&self, place: u32) -> Result<SteamId, ParseError> {
+        let place_mask = mask::INSTANCE & !mask::CHAT_TYPE;
+        let place = (place as u64) << shift::INSTANCE;
+        (place & !place_mask == 0)
+            .then_some(())
+            .ok_or(ParseError::Invalid(Field::Instance))?;
+        Ok(SteamId {
+            id: replace_bits(self.id, place_mask, place),
+        })
+    }
+
+    /// Returns both halves of the [Instance], the [ChatType] flags and the
+    /// 12-bit place below them, see [SteamIdBuilder::chat_instance_parts].
+    ///
+    /// Unlike [SteamId::chat_channel] this doesn't check the account type.
+    /// # Example
+    /// ```
+    /// use steamid::{ChatType, SteamId};
+    ///
+    /// let chat: SteamId = "[c:1:34967627]".parse().unwrap();
+    /// assert_eq!(chat.chat_instance_parts(), (ChatType::ClanChat, 0));
+    /// ```
+    pub fn chat_instance_parts(&self) -> (ChatType, u32) {
+        let place = (self.id & mask::INSTANCE & !mask::CHAT_TYPE) >> shift::INSTANCE;
+        (ChatType::from(self), place as u32)
+    }
+
+    /// Parses the `openid.claimed_id` field of a Steam OpenID response.
+    ///
+    /// Steam always sets this to the identity URL, so this is the same as
+    /// [SteamId::from_openid_url], the prefix is validated strictly.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let claimed_id = "https://steamcommunity.com/openid/id/76561197990953833";
+    /// let user = SteamId::from_openid_claimed_id(claimed_id).unwrap();
+    /// assert_eq!(u64::from(user), 76561197990953833)
+    /// ```
+    pub fn from_openid_claimed_id(claimed_id: &str) -> Result<SteamId, ParseError> {
+        SteamId::from_openid_url(claimed_id)
+    }
+
+    /// Returns the [IdFormat::Url] for this SteamId, but only if its
+    /// [AccountType] actually has a Steam Community page.
+    ///
+    /// See [AccountType::has_community_url] for which types qualify, any
+    /// other type returns [ParseError::Invalid] with [Field::AccountType].
+    /// Clan chats have no page of their own, so they link to their group's
+    /// page, see [SteamId::chat_to_group].
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(
+    ///     user.try_url().unwrap(),
+    ///     "http://steamcommunity.com/profiles/76561197990953833"
+    /// );
+    /// let server: SteamId = "[G:1:3331623]".parse().unwrap();
+    /// assert!(server.try_url().is_err());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn try_url(&self) -> Result<String, ParseError> {
+        let linked = match self.account_type() {
+            AccountType::Chat(ChatType::ClanChat) => self.chat_to_group(),
+            _ => *self,
+        };
+        self.account_type()
+            .has_community_url()
+            .then(|| IdFormat::Url(linked).to_string())
+            .ok_or(ParseError::Invalid(Field::AccountType))
+    }
+
+    /// Returns the same URL as [IdFormat::Url], but against `host` rather
+    /// than `steamcommunity.com`, for use with mirrors and proxies.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(
+    ///     user.url_with_host("localhost:8080"),
+    ///     "http://localhost:8080/profiles/76561197990953833"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn url_with_host(&self, host: &str) -> String {
+        let mut url = String::new();
+        // Writing into a String can't fail.
+        write_url_with_host(&mut url, self, host).expect("formatting into a String failed");
+        url
+    }
+
+    /// Returns the `steam://friends/add/` link that opens the Steam client's
+    /// add friend dialog for this SteamId.
+    ///
+    /// **Note**: This only works with the Steam client installed, and only
+    /// makes sense for [AccountType::Individual] accounts.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(user.friend_add_uri(), "steam://friends/add/76561197990953833")
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn friend_add_uri(&self) -> String {
+        format!("{FRIEND_ADD_URI}{}", self.id)
+    }
+
+    /// Parses a SteamId out of a Steam Community profile or group URL, like
+    /// those made by [IdFormat::Url].
+    ///
+    /// Anything after the id, such as `/games/?tab=all`, is ignored.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let url = "https://steamcommunity.com/profiles/76561197990953833/games/?tab=all";
+    /// let user = SteamId::from_url(url).unwrap();
+    /// assert_eq!(u64::from(user), 76561197990953833)
+    /// ```
+    pub fn from_url(s: &str) -> Result<SteamId, ParseError> {
+        Ok(parse_from_url(s, Mode::Default)?.finish())
+    }
+
+    /// Returns the `steam://friends/joinchat/` link that opens a group chat in
+    /// the Steam client, only for [ChatType::ClanChat] SteamIds.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let chat: SteamId = "[c:1:34967627]".parse().unwrap();
+    /// assert_eq!(
+    ///     chat.join_chat_uri().unwrap(),
+    ///     "steam://friends/joinchat/108156759836037195"
+    /// );
+    /// assert_eq!(SteamId::from(76561197990953833).join_chat_uri(), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn join_chat_uri(&self) -> Option<String> {
+        (self.account_type() == AccountType::Chat(ChatType::ClanChat))
+            .then(|| format!("{JOIN_CHAT_URI}{}", self.id))
+    }
+
+    /// Parses a SteamId out of a `steam://` client link, such as one made by
+    /// [SteamId::friend_add_uri] or [SteamId::join_chat_uri].
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from_steam_protocol("steam://friends/add/76561197990953833").unwrap();
+    /// assert_eq!(u64::from(user), 76561197990953833)
+    /// ```
+    pub fn from_steam_protocol(s: &str) -> Result<SteamId, ParseError> {
+        let s = s.trim();
+        let steam64 = s
+            .strip_prefix(FRIEND_ADD_URI)
+            .or_else(|| s.strip_prefix(JOIN_CHAT_URI))
+            .ok_or(ParseError::UnknownFormat)?;
+        steam64
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+            .then_some(())
+            .ok_or(ParseError::Invalid(Field::SteamId64))?;
+        Ok(parse_from_steamid64(steam64)?.finish())
+    }
+
+    /// Returns the identity URL Steam's OpenID provider uses for this SteamId.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let id = SteamId::from(76561197990953833);
+    /// assert_eq!(
+    ///     id.openid_url(),
+    ///     "https://steamcommunity.com/openid/id/76561197990953833"
+    /// )
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn openid_url(&self) -> String {
+        format!("{OPENID_URL}{}", self.id)
+    }
+
+    /// Parses a SteamId from a Steam OpenID identity URL.
+    ///
+    /// The input must begin with exactly `https://steamcommunity.com/openid/id/`
+    /// followed by a SteamId64, anything else is rejected.
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let url = "https://steamcommunity.com/openid/id/76561197990953833";
+    /// let id = SteamId::from_openid_url(url).unwrap();
+    /// assert_eq!(u64::from(id), 76561197990953833)
+    /// ```
+    pub fn from_openid_url(s: &str) -> Result<SteamId, ParseError> {
+        let steam64 = s
+            .strip_prefix(OPENID_URL)
+            .ok_or(ParseError::UnknownFormat)?;
+        steam64
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+            .then_some(())
+            .ok_or(ParseError::Invalid(Field::SteamId64))?;
+        Ok(parse_from_steamid64(steam64)?.finish())
+    }
+
+    /// Parses the `steamid` string field of a Steam Web API response, which
+    /// is always a SteamId64.
+    ///
+    /// Unlike [FromStr] no other format is accepted, so a SteamId2 or
+    /// SteamId3 fails with [ParseError::UnknownFormat].
+    /// # Example
+    /// ```
+    /// use steamid::SteamId;
+    ///
+    /// let user = SteamId::from_api_field("76561197990953833").unwrap();
+    /// assert_eq!(u64::from(user), 76561197990953833);
+    /// assert!(SteamId::from_api_field("[U:1:30688105]").is_err());
+    /// ```
+    pub fn from_api_field(s: &str) -> Result<SteamId, ParseError> {
+        (!s.is_empty()).then_some(()).ok_or(ParseError::Empty)?;
+        s.bytes()
+            .all(|b| b.is_ascii_digit())
+            .then_some(())
+            .ok_or(ParseError::UnknownFormat)?;
+        Ok(parse_from_steamid64(s)?.finish())
+    }
+
+    /// Returns whether formatting this SteamId with `fmt` and parsing it back
+    /// gives exactly the same SteamId.
+    ///
+    /// # Example
+    /// ```
+    /// use steamid::{OutputFormat, SteamId};
+    ///
+    /// let clan = SteamId::from(103582791464489035);
+    /// assert!(clan.round_trips(OutputFormat::SteamId64));
+    /// assert!(!clan.round_trips(OutputFormat::SteamId2));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn round_trips(&self, fmt: OutputFormat) -> bool {
+        IdFormat::new(fmt, *self)
+            .to_string()
+            .parse::<SteamId>()
+            .is_ok_and(|parsed| parsed.id == self.id)
+    }
+
+    /// Returns every [OutputFormat] that makes sense for this SteamId's
+    /// [AccountType].
+    ///
+    /// SteamId2 and the account id only codes are limited to
+    /// [AccountType::Individual], and URLs to types with a community page.
+    /// # Example
+    /// ```
+    /// use steamid::{OutputFormat, SteamId};
+    ///
+    /// let clan = SteamId::from(103582791464489035);
+    /// assert!(clan.available_formats().contains(&OutputFormat::Url));
+    /// assert!(!clan.available_formats().contains(&OutputFormat::SteamId2));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn available_formats(&self) -> Vec<OutputFormat> {
+        let individual = self.account_type() == AccountType::Individual;
+        let community = self.account_type().has_community_url();
+        [
+            (OutputFormat::SteamId64, true),
+            (OutputFormat::SteamId2, individual),
+            (OutputFormat::SteamId2Legacy, individual),
+            (OutputFormat::SteamId3, true),
+            (OutputFormat::Url, community),
+            (OutputFormat::UrlHttps, community),
+            (OutputFormat::FriendCode, individual),
+            (OutputFormat::InviteCode, individual),
+        ]
+        .into_iter()
+        .filter_map(|(format, available)| available.then_some(format))
+        .collect()
+    }
+}
+
+// Let users cast directly from a u64 to a SteamId if they want.
+impl From<u64> for SteamId {
+    fn from(id: u64) -> Self {
+        SteamId::new(id)
+    }
+}
+
+impl From<SteamId> for u64 {
+    fn from(steamid: SteamId) -> Self {
+        steamid.id
+    }
+}
+
+/// Reinterprets the SteamId64's bits as an [i64], for databases without an
+/// unsigned 64-bit column type.
+///
+/// **Note**: Any SteamId64 with the highest bit set, such as universes above
+/// `127`, becomes negative. Convert back with [SteamId]'s `TryFrom<i64>`
+/// rather than through [u64].
+impl From<SteamId> for i64 {
+    fn from(steamid: SteamId) -> Self {
+        steamid.id as i64
+    }
+}
+
+/// Reinterprets an [i64] as a SteamId64, the inverse of `From<SteamId> for
+/// i64`.
+///
+/// The value is read as the two's-complement bit pattern of a [u64], and is
+/// accepted when that pattern is well-formed, whatever its sign:
+/// - The account type is known, and not [AccountType::Invalid], otherwise
+///   this fails with [ParseError::Invalid] for [Field::AccountType].
+/// - The instance is a known [Instance], otherwise this fails with
+///   [ParseError::Invalid] for [Field::Instance].
+///
+/// The [Universe] is not checked. Universes of `128` and above, which are
+/// stored as negative values, are accepted even though they read back as
+/// [Universe::Unspecified]. Values such as `-1` or `i64::MAX` are rejected.
+///
+/// **Note**: This is `TryFrom` rather than `From` because a second `From`
+/// integer impl would stop `SteamId::from(76561197990953833)` from inferring
+/// the literal as a [u64].
+/// # Example
+/// ```
+/// use steamid::SteamId;
+///
+/// let user = SteamId::from(76561197990953833);
+/// let stored = i64::from(user);
+/// assert_eq!(SteamId::try_from(stored), Ok(user));
+/// assert!(SteamId::try_from(-1i64).is_err());
+///
+/// let stored = i64::from(SteamId::from(0x80 << 56 | 0x0010_0001_01D4_4369));
+/// assert!(stored.is_negative());
+/// assert_eq!(SteamId::try_from(stored).unwrap().account_id(), 30688105);
+/// ```
+impl TryFrom<i64> for SteamId {
+    type Error = ParseError;
+
+    fn try_from(id: i64) -> Result<Self, Self::Error> {
+        let steamid = SteamId { id: id as u64 };
+        AccountType::try_from_raw(steamid.account_type_raw())
+            .ok()
+            .filter(|&account_type| account_type != AccountType::Invalid)
+            .ok_or(ParseError::Invalid(Field::AccountType))?;
+        Instance::try_from_raw(steamid.instance_raw())?;
+        Ok(steamid)
+    }
+}
+
+impl FromStr for SteamId {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(SteamIdBuilder::from_str(s)?.finish())
+    }
+}
+
+/// Displays the steamID64, the same as [IdFormat::SteamId64].
+///
+/// Any other format still needs to go through [IdFormat].
+/// # Example
+/// ```
+/// use steamid::SteamId;
+///
+/// let user = SteamId::from(76561197990953833);
+/// assert_eq!(format!("{user}"), "76561197990953833");
+/// ```
+impl Display for SteamId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_steamid64(f, self)
+    }
+}
+
+/// Used to wrap a [SteamId] to specify output formatting, since [SteamId]'s
+/// own [Display] only writes the steamID64.
+///
+/// # Examples #
+///
+/// ```
+/// use steamid::{IdFormat, SteamIdBuilder, SteamId};
+///
+/// let user = SteamIdBuilder::new()
+///     .account_number(15344052)
+///     .authentication_server(1)
+///     .account_type('U')
+///     .finish();
+///
+/// assert_eq!(format!("{}",  IdFormat::SteamId64(user)), "76561197990953833");
+/// assert_eq!(format!("{}",  IdFormat::SteamId2(user)), "STEAM_1:1:15344052");
+/// assert_eq!(format!("{}",  IdFormat::SteamId3(user)), "[U:1:30688105]");
+/// assert_eq!(format!("{}",  IdFormat::Url(SteamId::from(103582791464489035))), "http://steamcommunity.com/gid/[g:1:34967627]");
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdFormat {
+    /// The full 64-bit "steamID64"
+    ///
+    /// Example: `76561197960265731`
+    SteamId64(SteamId),
+    /// Older "steamID" format used commonly.
+    ///
+    /// Example: `STEAM_1:1:15344052`
+    SteamId2(SteamId),
+    /// This is different from normal `SteamId2` in that the universe is always
+    /// shown as `0`.
+    ///
+    /// ## Example ##
+    /// For SteamId3 `[U:1:3]`
+    /// - SteamId2  = `STEAM_1:1:1`
+    /// - SteamId2Legacy = `STEAM_0:1:1`
+    SteamId2Legacy(SteamId),
+    /// Modern preferred standard.
+    ///
+    /// Game servers also include their instance as a fourth field, when it's
+    /// non-zero for [AccountType::GameServer], and always for
+    /// [AccountType::AnonGameServer].
+    ///
+    /// Example: `[U:1:30688105]`, `[A:1:3331623:4]`
+    SteamId3(SteamId),
+    /// Web address for the SteamId.
+    ///
+    /// ## Example ##
+    /// `http://steamcommunity.com/profiles/76561197990953833`
+    ///
+    /// `http://steamcommunity.com/gid/[g:1:34967627]`
+    Url(SteamId),
+    /// Same as [IdFormat::Url], but using `https://`.
+    ///
+    /// ## Example ##
+    /// `https://steamcommunity.com/profiles/76561197990953833`
+    UrlHttps(SteamId),
+    /// CS:GO / CS2 friend code, which only covers the account id.
+    ///
+    /// Example: `SUCVS-FADA`
+    FriendCode(SteamId),
+    /// Steam invite code, as used by `https://s.team/p/` links, which only
+    /// covers the account id.
+    ///
+    /// Example: `cv-dgb`
+    InviteCode(SteamId),
+}
+
+impl IdFormat {
+    /// Wraps `id` in the [IdFormat] variant matching `fmt`.
+    ///
+    /// # Example
+    /// ```
+    /// use steamid::{IdFormat, OutputFormat, SteamId};
+    ///
+    /// let user = SteamId::from(76561197990953833);
+    /// assert_eq!(IdFormat::new(OutputFormat::SteamId3, user), IdFormat::SteamId3(user));
+    /// ```
+    pub fn new(fmt: OutputFormat, id: SteamId) -> IdFormat {
+        match fmt {
+            OutputFormat::SteamId64 => IdFormat::SteamId64(id),
+            OutputFormat::SteamId2 => IdFormat::SteamId2(id),
+            OutputFormat::SteamId2Legacy => IdFormat::SteamId2Legacy(id),
+            OutputFormat::SteamId3 => IdFormat::SteamId3(id),
+            OutputFormat::Url => IdFormat::Url(id),
+            OutputFormat::UrlHttps => IdFormat::UrlHttps(id),
+            OutputFormat::FriendCode => IdFormat::FriendCode(id),
+            OutputFormat::InviteCode => IdFormat::InviteCode(id),
+        }
+    }
+}
+
+/// The output formats of [IdFormat], without a SteamId attached.
+///
+/// Used to pick a format for functions such as [SteamId::round_trips], see
+/// the matching [IdFormat] variant for what each one looks like.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutputFormat {
+    /// See [IdFormat::SteamId64].
+    SteamId64,
+    /// See [IdFormat::SteamId2].
+    SteamId2,
+    /// See [IdFormat::SteamId2Legacy].
+    SteamId2Legacy,
+    /// See [IdFormat::SteamId3].
+    SteamId3,
+    /// See [IdFormat::Url].
+    Url,
+    /// See [IdFormat::UrlHttps].
+    UrlHttps,
+    /// See [IdFormat::FriendCode].
+    FriendCode,
+    /// See [IdFormat::InviteCode].
+    InviteCode,
+}
+
+impl Display for IdFormat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            IdFormat::SteamId64(v) => write_steamid64(f, v),
+            IdFormat::SteamId2(v) => write_steamid2(f, v),
+            IdFormat::SteamId2Legacy(v) => write_steamid2_legacy(f, v),
+            IdFormat::SteamId3(v) => write_steamid3(f, v),
+            IdFormat::Url(v) => write_url(f, v),
+            IdFormat::UrlHttps(v) => write_url_https(f, v),
+            IdFormat::FriendCode(v) => write_friend_code(f, v.account_id()),
+            IdFormat::InviteCode(v) => write_invite_code(f, v.account_id()),
+        }
+    }
+}
+
+// Each format is written by its own function so they can be shared between
+// `IdFormat` and the monomorphized `render`.
+
+pub(crate) fn write_steamid64<W: fmt::Write>(w: &mut W, v: &SteamId) -> fmt::Result {
+    write!(w, "{}", v.id)
+}
+
+/// Returns whether a SteamId2 and SteamId3 string pack the account id the
+/// same way, false if either can't be read.
+#[cfg(feature = "alloc")]
+fn packing_matches(steam2: &str, steam3: &str) -> bool {
+    let mut steam2 = steam2.split(':').skip(1).map(str::parse::<u64>);
+    let steam3_z = steam3
+        .trim_matches(['[', ']'])
+        .split(':')
+        .nth(2)
+        .map(str::parse::<u64>);
+    match (steam2.next(), steam2.next(), steam3_z) {
+        (Some(Ok(y)), Some(Ok(z)), Some(Ok(steam3_z))) => steam3_z == (z << 1 | y),
+        _ => false,
+    }
+}
+
+pub(crate) fn write_steamid2<W: fmt::Write>(w: &mut W, v: &SteamId) -> fmt::Result {
+    write!(
+        w,
+        "STEAM_{}:{}:{}",
+        u8::from(v.universe()),
+        v.authentication_server(),
+        v.account_number()
+    )
+}
+
+pub(crate) fn write_steamid2_legacy<W: fmt::Write>(w: &mut W, v: &SteamId) -> fmt::Result {
+    write!(
+        w,
+        "STEAM_{}:{}:{}",
+        0,
+        v.authentication_server(),
+        v.account_number()
+    )
+}
+
+pub(crate) fn write_steamid3<W: fmt::Write>(w: &mut W, v: &SteamId) -> fmt::Result {
+    let account_type = AccountType::from(*v);
+    write!(
+        w,
+        "[{}:{}:{}",
+        char::from(account_type),
+        u8::from(v.universe()),
+        v.id & (mask::AUTH_SERVER | mask::ACCOUNT_NUMBER)
+    )?;
+    // Game servers include their instance, as SteamKit does.
+    let instance = (v.id & mask::INSTANCE) >> shift::INSTANCE;
+    match account_type {
+        AccountType::AnonGameServer => write!(w, ":{instance}]"),
+        AccountType::GameServer if instance != 0 => write!(w, ":{instance}]"),
+        _ => w.write_str("]"),
+    }
+}
+
+pub(crate) fn write_url<W: fmt::Write>(w: &mut W, v: &SteamId) -> fmt::Result {
+    write_url_with_host(w, v, COMMUNITY_HOST)
+}
+
+fn write_url_https<W: fmt::Write>(w: &mut W, v: &SteamId) -> fmt::Result {
+    w.write_str("https://")?;
+    write_url_path(w, v, COMMUNITY_HOST)
+}
+
+fn write_url_with_host<W: fmt::Write>(w: &mut W, v: &SteamId, host: &str) -> fmt::Result {
+    w.write_str("http://")?;
+    write_url_path(w, v, host)
+}
+
+/// Writes everything after the scheme, picking the group or profile path.
+fn write_url_path<W: fmt::Write>(w: &mut W, v: &SteamId, host: &str) -> fmt::Result {
+    w.write_str(host)?;
+    match v.account_type() {
+        AccountType::Clan => {
+            w.write_str(GROUP_PATH)?;
+            write_steamid3(w, v)
+        }
+        _ => {
+            w.write_str(PROFILE_PATH)?;
+            write_steamid64(w, v)
+        }
+    }
+}
+
+/////////////////////////////////////////////////////////////////////////////
+// Unit Testing
+/////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::hash::{Hash, Hasher};
+
+    use super::packing_matches;
+    use crate::errors::{BuildError, Field, ParseError};
+    use crate::*;
+
+    /// Ensures our documentation and everything line up with the actual defaults
+    #[test]
+    fn defaults() {
+        let user = SteamIdBuilder::new().finish();
+        assert_eq!(user.id, 76561197960265728, "Incorrect default SteamId.");
+    }
+
+    #[test]
+    fn anonymous_game_server() {
+        let server = SteamIdBuilder::anonymous_game_server().finish();
+        assert_eq!(server.account_type(), AccountType::AnonGameServer);
+        assert_eq!(server.instance(), Instance::None(ChatType::None));
+        assert_eq!(server.universe(), Universe::Public);
+        assert_eq!(server.id, 90071992547409920);
+    }
+
+    #[test]
+    fn builder_copy_field() {
+        let public = SteamId::from(76561197990953833);
+        let beta = SteamIdBuilder::new()
+            .account_number(1)
+            .universe(Universe::Beta)
+            .instance(4)
+            .finish();
+
+        let copied = SteamIdBuilder::from(public)
+            .copy_field(Field::Universe, &beta)
+            .finish();
+        assert_eq!(copied.universe(), Universe::Beta);
+        assert_eq!(copied.account_number(), public.account_number());
+        assert_eq!(copied.instance(), public.instance());
+
+        let copied = SteamIdBuilder::from(copied)
+            .copy_field(Field::Instance, &beta)
+            .finish();
+        assert_eq!(copied.instance(), beta.instance());
+
+        let copied = SteamIdBuilder::from(public)
+            .copy_field(Field::SteamId64, &beta)
+            .finish();
+        assert_eq!(copied, beta);
+    }
+
+    #[test]
+    fn builder_set_fields() {
+        assert!(SteamIdBuilder::new().set_fields().is_empty());
+        let builder = SteamIdBuilder::new()
+            .account_number(15344052)
+            .universe(Universe::Beta);
+        assert_eq!(
+            builder.set_fields(),
+            vec![Field::AccountNumber, Field::Universe]
+        );
+        // Setting the default value again isn't a change.
+        assert!(SteamIdBuilder::new().instance(1).set_fields().is_empty());
+        assert_eq!(
+            SteamIdBuilder::new().account_type('g').set_fields(),
+            vec![Field::Instance, Field::AccountType]
+        );
+    }
+
+    #[test]
+    fn builder_validate() {
+        let user = SteamIdBuilder::new().account_number(1);
+        assert!(user.validate().is_empty());
+        let lobby = SteamIdBuilder::new().account_type('L');
+        assert!(lobby.validate().is_empty());
+        let server = SteamIdBuilder::anonymous_game_server();
+        assert!(server.validate().is_empty());
+
+        let broken = SteamIdBuilder::from(SteamId::from_raw_fields(9, 1, 3, 0));
+        assert_eq!(
+            broken.validate(),
+            vec![
+                (Field::Universe, "unknown universe"),
+                (Field::Instance, "unknown instance"),
+                (Field::AccountNumber, "individual account number is zero"),
+            ]
+        );
+
+        let broken = SteamIdBuilder::from(SteamId::from_raw_fields(1, 14, 3 << 12, 1));
+        assert_eq!(
+            broken.validate(),
+            vec![
+                (Field::AccountType, "unknown account type"),
+                (Field::Instance, "unknown chat type"),
+            ]
+        );
+
+        let broken = SteamIdBuilder::new()
+            .account_number(1)
+            .instance(Instance::Desktop(ChatType::Lobby))
+            .account_type_preserve_bits('I');
+        assert_eq!(
+            broken.validate(),
+            vec![
+                (Field::AccountType, "account type is invalid"),
+                (Field::Instance, "chat type set on a non-chat account"),
+            ]
+        );
+    }
+
+    /// Makes sure builder functions are changing internal values correctly.
+    #[test]
+    fn builder_authentication_server() {
+        let reference = SteamIdBuilder::new().finish().id;
+
+        let set_one = SteamIdBuilder::new().authentication_server(1);
+        assert_eq!(set_one.id, reference | mask::AUTH_SERVER);
+
+        let set_high = SteamIdBuilder::new().authentication_server(8);
+        assert_eq!(set_high.id, reference | mask::AUTH_SERVER);
+
+        let set_zero = SteamIdBuilder::new().authentication_server(0);
+        assert_eq!(set_zero.id, reference & !mask::AUTH_SERVER);
+    }
+
+    /// Makes sure builder functions are changing internal values correctly.
+    #[test]
+    fn builder_account_number() {
+        let alfred = SteamIdBuilder::new().account_number(1).finish();
+        assert_eq!(alfred.id, 76561197960265730);
+    }
+
+    /// Makes sure builder functions are changing internal values correctly.
+    #[test]
+    fn builder_account_type() {
+        // Internally, `account_type` uses `account_type_preserve_bits` so,
+        // I consider that covered by these tests.
+
+        let invalid = SteamIdBuilder::new().account_type('I').finish();
+        assert_eq!(invalid.id, 72057598332895232);
+        let invalid = SteamIdBuilder::new().account_type(0).finish();
+        assert_eq!(invalid.id, 72057598332895232);
+
+        let user = SteamIdBuilder::new().account_type('U').finish();
+        assert_eq!(user.id, 76561197960265728);
+        let user = SteamIdBuilder::new().account_type(1).finish();
+        assert_eq!(user.id, 76561197960265728);
+
+        let multiseat = SteamIdBuilder::new().account_type('M').finish();
+        assert_eq!(multiseat.id, 81064793292668928);
+        let multiseat = SteamIdBuilder::new().account_type(2).finish();
+        assert_eq!(multiseat.id, 81064793292668928);
+
+        let game_server = SteamIdBuilder::new().account_type('G').finish();
+        assert_eq!(game_server.id, 85568392920039424);
+        let game_server = SteamIdBuilder::new().account_type(3).finish();
+        assert_eq!(game_server.id, 85568392920039424);
+
+        let anon_game_server = SteamIdBuilder::new().account_type('A').finish();
+        assert_eq!(anon_game_server.id, 90071992547409920);
+        let anon_game_server = SteamIdBuilder::new().account_type(4).finish();
+        assert_eq!(anon_game_server.id, 90071992547409920);
+
+        let pending = SteamIdBuilder::new().account_type('P').finish();
+        assert_eq!(pending.id, 94575592174780416);
+        let pending = SteamIdBuilder::new().account_type(5).finish();
+        assert_eq!(pending.id, 94575592174780416);
+
+        let content_server = SteamIdBuilder::new().account_type('C').finish();
+        assert_eq!(content_server.id, 99079191802150912);
+        let content_server = SteamIdBuilder::new().account_type(6).finish();
+        assert_eq!(content_server.id, 99079191802150912);
+
+        let clan = SteamIdBuilder::new().account_type('g').finish();
+        assert_eq!(clan.id, 103582791429521408);
+        let clan = SteamIdBuilder::new().account_type(7).finish();
+        assert_eq!(clan.id, 103582791429521408);
+
+        let chat = SteamIdBuilder::new().account_type('L').finish();
+        assert_eq!(chat.id, 108121575428980736);
+
+        let chat = SteamIdBuilder::new().account_type('T').finish();
+        assert_eq!(chat.id, 108103983242936320);
+
+        let chat = SteamIdBuilder::new().account_type('c').finish();
+        assert_eq!(chat.id, 108156759801069568);
+
+        // Console User aka "P2P SuperSeeder" does not have a char.
+        let console = SteamIdBuilder::new().account_type(9).finish();
+        assert_eq!(console.id, 112589990684262400);
+
+        let anon = SteamIdBuilder::new().account_type('a').finish();
+        assert_eq!(anon.id, 117093590311632896);
+        let anon = SteamIdBuilder::new().account_type(10).finish();
+        assert_eq!(anon.id, 117093590311632896);
+    }
+
+    /// account type has side effect we need to verify are following spec.
+    #[test]
+    fn builder_account_type_side_effects() {
+        let builder = SteamIdBuilder::new();
+
+        // Baselines, default instance is 1.
+        assert_eq!(
+            u32::from(builder.finish().instance()),
+            1,
+            "Baseline instance incorrect."
+        );
+        assert_eq!(u32::from(builder.account_type('I').finish().instance()), 1);
+
+        // Test side-effecting
+        assert_eq!(
+            u32::from(builder.account_type('c').finish().instance()),
+            16384,
+            "Chat account_type not setting instance bits."
+        );
+        assert_eq!(
+            u32::from(builder.account_type('c').finish().instance()),
+            16384,
+            "Chat account_type not resetting instance bits."
+        );
+        assert_eq!(
+            u32::from(builder.instance(4).account_type('c').finish().instance()),
+            16384,
+            "Chat account_type not resetting instance bits."
+        );
+        assert_eq!(
+            u32::from(builder.instance(4).account_type('A').finish().instance()),
+            0,
+            "Chat account_type not resetting instance bits."
+        );
+    }
+
+    /// Makes sure builder functions are changing internal values correctly.
+    #[test]
+    fn builder_instance() {
+        let alfred = SteamIdBuilder::new().account_number(1).instance(1).finish();
+        assert_eq!(alfred.id, 76561197960265730);
+
+        let alfred = SteamIdBuilder::new().account_number(1).instance(2).finish();
+        assert_eq!(alfred.id, 76561202255233026);
+
+        let alfred = SteamIdBuilder::new().account_number(1).instance(4).finish();
+        assert_eq!(alfred.id, 76561210845167618);
+
+        let alfred = SteamIdBuilder::new()
+            .account_number(1)
+            .instance(Instance::Web(ChatType::default()))
+            .finish();
+        assert_eq!(alfred.id, 76561210845167618);
+    }
+
+    /// Makes sure builder functions are changing internal values correctly.
+    #[test]
+    fn builder_instance_shorthand() {
+        let builder = SteamIdBuilder::new().account_number(1);
+        assert_eq!(
+            builder.none_instance().finish().id,
+            builder.instance(0).finish().id
+        );
+        assert_eq!(
+            builder.desktop().finish().id,
+            builder.instance(1).finish().id
+        );
+        assert_eq!(
+            builder.console().finish().id,
+            builder.instance(2).finish().id
+        );
+        assert_eq!(builder.web().finish().id, builder.instance(4).finish().id);
+
+        // Chat bits are kept.
+        let lobby = SteamIdBuilder::new().account_type('L');
+        assert_eq!(
+            lobby.desktop().finish().instance(),
+            Instance::Desktop(ChatType::Lobby)
+        );
+        assert_eq!(
+            lobby.console().finish().instance(),
+            Instance::Console(ChatType::Lobby)
+        );
+        assert_eq!(
+            lobby.web().finish().instance(),
+            Instance::Web(ChatType::Lobby)
+        );
+        assert_eq!(
+            lobby.web().none_instance().finish().instance(),
+            Instance::None(ChatType::Lobby)
+        );
+    }
+
+    /// Makes sure builder functions are changing internal values correctly.
+    #[test]
+    fn builder_universe() {
+        let builder = SteamIdBuilder::new().account_number(1);
+        assert_eq!(builder.universe(0).finish().id, 4503603922337794);
+        assert_eq!(builder.universe(1).finish().id, 76561197960265730);
+        assert_eq!(
+            builder.universe(Universe::Unspecified).finish().id,
+            4503603922337794
+        );
+        assert_eq!(
+            builder.universe(Universe::Public).finish().id,
+            76561197960265730
+        );
+    }
+
+    /// Ensures going from Builder to Id directly and back doesn't change anything.
+    #[test]
+    fn from_reciprocity() {
+        let alfred = SteamId::from(4503603922337794);
+        assert_eq!(SteamIdBuilder::from(alfred).id, 4503603922337794);
+        assert_eq!(SteamIdBuilder::from(alfred).id, 4503603922337794);
+    }
+
+    #[test]
+    fn equality() {
+        let alfred = SteamIdBuilder::new().account_number(1).instance(1).finish();
+        assert!(alfred == SteamId::from(76561197960265730));
+        assert!(alfred != SteamId::from(0));
+        let hash = |id: SteamId| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            id.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert!(hash(alfred) == hash(SteamId::from(76561197960265730)));
+    }
+
+    #[test]
+    fn normalize_all() {
+        let user = SteamIdBuilder::new().account_number(1).finish();
+        let clan = SteamIdBuilder::new().account_number(1).account_type('g');
+        let mut ids = [
+            user,
+            SteamIdBuilder::from(user).universe(Universe::Beta).finish(),
+            SteamIdBuilder::from(user).instance(4).finish(),
+            SteamIdBuilder::from(user)
+                .universe(Universe::Unspecified)
+                .instance(Instance::Console(ChatType::Lobby))
+                .finish(),
+            clan.universe(Universe::Dev).finish(),
+        ];
+        crate::normalize_all(&mut ids);
+        assert!(ids[..4].iter().all(|&id| id == user));
+        // Only the universe changes for non-individuals.
+        assert_eq!(ids[4], clan.finish());
+    }
+
+    #[test]
+    fn hex_bytes_reciprocity() {
+        for raw in [76561197990953833, 103582791464489035, 0, u64::MAX] {
+            let hex = format!("{raw:016x}");
+            assert_eq!(SteamId::from_hex_bytes(&hex), Ok(SteamId::from(raw)));
+            let hex = format!("{raw:016X}");
+            assert_eq!(SteamId::from_hex_bytes(&hex), Ok(SteamId::from(raw)));
+        }
+
+        assert_eq!(
+            SteamId::from_hex_bytes("110000101d44a69"),
+            Err(ParseError::UnknownFormat)
+        );
+        assert_eq!(
+            SteamId::from_hex_bytes("0x10000101d44a69"),
+            Err(ParseError::Invalid(Field::SteamId64))
+        );
+        assert_eq!(
+            SteamId::from_hex_bytes("+110000101d44a69"),
+            Err(ParseError::Invalid(Field::SteamId64))
+        );
+        assert_eq!(SteamId::from_hex_bytes(""), Err(ParseError::UnknownFormat));
+    }
+
+    #[test]
+    fn cs2_status_token() {
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(SteamId::from_cs2_status_token("#30688105"), Ok(user));
+        assert_eq!(SteamId::from_cs2_status_token("30688105"), Ok(user));
+
+        assert_eq!(SteamId::from_cs2_status_token("#"), Err(ParseError::Empty));
+        assert_eq!(
+            SteamId::from_cs2_status_token("#U:1:30688105"),
+            Err(ParseError::Invalid(Field::AccountNumber))
+        );
+        assert_eq!(
+            SteamId::from_cs2_status_token("#+30688105"),
+            Err(ParseError::Invalid(Field::AccountNumber))
+        );
+        assert_eq!(
+            SteamId::from_cs2_status_token("#4294967296"),
+            Err(ParseError::Invalid(Field::AccountNumber))
+        );
+    }
+
+    #[test]
+    fn steam3_z() {
+        for raw in [76561197990953833, 103582791464489035, 85568392923371047] {
+            let id = SteamId::from(raw);
+            let steam3 = IdFormat::SteamId3(id).to_string();
+            let z = steam3.trim_end_matches(']').rsplit(':').next().unwrap();
+            assert_eq!(id.steam3_z().to_string(), z);
+        }
+    }
+
+    #[test]
+    fn field_bits() {
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(user.field_bits(Field::Universe), 0x0100000000000000);
+        assert_eq!(user.field_bits(Field::AccountType), 0x0010000000000000);
+        assert_eq!(user.field_bits(Field::Instance), 0x0000000100000000);
+        assert_eq!(
+            user.field_bits(Field::AccountNumber),
+            (user.account_number() as u64) << 1
+        );
+        assert_eq!(user.field_bits(Field::AuthServer), 1);
+        assert_eq!(user.field_bits(Field::SteamId64), user.id);
+    }
+
+    #[test]
+    fn same_universe() {
+        let public = SteamId::from(76561197990953833);
+        let clan = SteamId::from(103582791464489035);
+        assert!(public.same_universe(&clan));
+
+        let beta = SteamIdBuilder::from(public)
+            .universe(Universe::Beta)
+            .finish();
+        assert!(!public.same_universe(&beta));
+
+        // Both of these would convert into `Universe::Unspecified`.
+        let other = SteamId::from_raw_fields(200, 1, 1, 30688105);
+        let unspecified = SteamIdBuilder::from(public).universe(0).finish();
+        assert_eq!(other.universe(), unspecified.universe());
+        assert!(!other.same_universe(&unspecified));
+        assert!(other.same_universe(&SteamId::from_raw_fields(200, 7, 0, 1)));
+    }
+
+    #[test]
+    fn raw_fields() {
+        let user = SteamId::from_raw_fields(1, 1, 1, 30688105);
+        assert_eq!(user, SteamId::from(76561197990953833));
+
+        // Account type 15 has no AccountType, but should survive untouched.
+        let unknown = SteamId::from_raw_fields(1, 15, 0x54321, 30688105);
+        assert_eq!((unknown.id & mask::ACCOUNT_TYPE) >> shift::ACCOUNT_TYPE, 15);
+        assert_eq!((unknown.id & mask::INSTANCE) >> shift::INSTANCE, 0x54321);
+        assert_eq!(unknown.universe(), Universe::Public);
+        assert_eq!(unknown.steam3_z(), 30688105);
+
+        // Values too wide for their field are truncated.
+        let wide = SteamId::from_raw_fields(0, 0xFF, u32::MAX, 0);
+        assert_eq!(wide.id, mask::ACCOUNT_TYPE | mask::INSTANCE);
+    }
+
+    #[test]
+    fn proto_reciprocity() {
+        for raw in [76561197990953833, 103582791464489035, 0, u64::MAX] {
+            let id = SteamId::from_proto(raw);
+            assert_eq!(id, SteamId::from(raw));
+            assert_eq!(id.to_proto(), raw);
+        }
+    }
+
+    #[test]
+    fn binary_reciprocity() {
+        for raw in [76561197990953833, 103582791464489035, 0, u64::MAX] {
+            let id = SteamId::from(raw);
+            assert_eq!(SteamId::decode(&id.encode()), Ok(id));
+        }
+        let encoded = SteamId::from(76561197990953833).encode();
+        assert_eq!(SteamId::decode(&encoded[..7]), Err(ParseError::TooShort));
+        assert_eq!(SteamId::decode(&[]), Err(ParseError::Empty));
+        assert_eq!(SteamId::decode(&[0; 9]), Err(ParseError::UnknownFormat));
+    }
+
+    #[test]
+    fn u32_pair_reciprocity() {
+        for raw in [76561197990953833, 103582791464489035, 0, u64::MAX] {
+            let id = SteamId::from(raw);
+            let (low, high) = id.to_u32_pair();
+            assert_eq!(SteamId::from_u32_pair(low, high), id);
+        }
+        let (low, _) = SteamId::from(76561197990953833).to_u32_pair();
+        assert_eq!(low, SteamId::from(76561197990953833).steam3_z());
+    }
+
+    #[test]
+    fn accountid_instance_reciprocity() {
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(user.to_accountid_instance(), "30688105:1");
+        assert_eq!(SteamId::from_accountid_instance("30688105:1"), Ok(user));
+
+        let web = SteamIdBuilder::from(user).instance(4).finish();
+        assert_eq!(web.to_accountid_instance(), "30688105:4");
+        assert_eq!(
+            SteamId::from_accountid_instance(&web.to_accountid_instance()),
+            Ok(web)
+        );
+
+        assert_eq!(
+            SteamId::from_accountid_instance("30688105:1048575").map(|v| v.to_accountid_instance()),
+            Ok("30688105:1048575".to_string())
+        );
+        assert_eq!(
+            SteamId::from_accountid_instance("30688105:1048576"),
+            Err(ParseError::Invalid(Field::Instance))
+        );
+        assert_eq!(
+            SteamId::from_accountid_instance("30688105"),
+            Err(ParseError::TooShort)
+        );
+        assert_eq!(
+            SteamId::from_accountid_instance("-1:1"),
+            Err(ParseError::Invalid(Field::AccountNumber))
+        );
+        assert_eq!(SteamId::from_accountid_instance(""), Err(ParseError::Empty));
+    }
+
+    #[test]
+    fn verify_packing() {
+        for raw in [76561197990953833, 103582791464489035, 0, u64::MAX] {
+            assert!(SteamId::from(raw).verify_packing());
+        }
+        let from_steam2: SteamId = "STEAM_1:1:15344052".parse().unwrap();
+        let from_steam3: SteamId = "[U:1:30688105]".parse().unwrap();
+        assert!(from_steam2.verify_packing());
+        assert_eq!(from_steam2.steam3_z(), from_steam3.steam3_z());
+
+        assert!(packing_matches("STEAM_1:1:15344052", "[U:1:30688105]"));
+        assert!(packing_matches("STEAM_0:0:1", "[A:1:2:5]"));
+        // Auth bit and account number swapped, or shifted the wrong way.
+        assert!(!packing_matches("STEAM_1:0:15344052", "[U:1:30688105]"));
+        assert!(!packing_matches("STEAM_1:1:30688105", "[U:1:30688105]"));
+        assert!(!packing_matches("STEAM_1:1", "[U:1:30688105]"));
+        assert!(!packing_matches("STEAM_1:1:15344052", "[U:1:]"));
+    }
+
+    #[test]
+    fn into_inner() {
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(user.into_inner(), 76561197990953833);
+        assert_eq!(user.into_inner(), u64::from(user));
+    }
+
+    #[test]
+    fn is_well_known() {
+        assert!(well_known::GABE_NEWELL.is_well_known());
+        assert!(SteamIdBuilder::anonymous_game_server()
+            .finish()
+            .is_well_known());
+        assert!(!SteamId::from(76561197990953833).is_well_known());
+        // Instances matter.
+        let gabe_web = SteamIdBuilder::from(well_known::GABE_NEWELL)
+            .instance(4)
+            .finish();
+        assert!(!gabe_web.is_well_known());
+    }
+
+    #[test]
+    fn avatar_seed() {
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(user.avatar_seed(), "f36623c60b0277c6");
+        // Only the account id matters.
+        let web = SteamIdBuilder::from(user).instance(4).universe(2).finish();
+        assert_eq!(web.avatar_seed(), user.avatar_seed());
+        let other = SteamId::from(76561198930384249);
+        assert_ne!(other.avatar_seed(), user.avatar_seed());
+    }
+
+    #[test]
+    fn to_group() {
+        let base = SteamId::from(76561197990953833);
+        let group = base.to_group();
+        assert_eq!(group, SteamIdBuilder::from(base).account_type('g').finish());
+        assert_eq!(group.account_type(), AccountType::Clan);
+        assert_eq!(
+            IdFormat::Url(group).to_string(),
+            "http://steamcommunity.com/gid/[g:1:30688105]"
+        );
+    }
+
+    #[test]
+    fn split_chat() {
+        let chat = SteamId::from(108156759836037195);
+        let (base, chat_type) = chat.split_chat();
+        assert_eq!(chat_type, ChatType::ClanChat);
+        assert_eq!(base.id, chat.id & !mask::CHAT_TYPE);
+        assert_eq!(base.instance(), Instance::None(ChatType::None));
+        assert_eq!(base.steam3_z(), chat.steam3_z());
+
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(user.split_chat(), (user, ChatType::None));
+    }
+
+    #[test]
+    fn same_chat_room() {
+        let clan_chat = SteamId::from(108156759836037195);
+        let lobby = SteamIdBuilder::from(clan_chat)
+            .instance(Instance::None(ChatType::Lobby))
+            .finish();
+        let matchmaking = SteamIdBuilder::from(clan_chat)
+            .instance(Instance::None(ChatType::MatchMakingLobby))
+            .finish();
+        assert!(clan_chat.same_chat_room(&lobby));
+        assert!(lobby.same_chat_room(&matchmaking));
+
+        // Other fields still have to match.
+        let other_place = SteamIdBuilder::from(clan_chat)
+            .instance(Instance::Web(ChatType::ClanChat))
+            .finish();
+        assert!(!clan_chat.same_chat_room(&other_place));
+        let other_room = SteamIdBuilder::from(clan_chat).account_number(1).finish();
+        assert!(!clan_chat.same_chat_room(&other_room));
+    }
+
+    #[test]
+    fn amxx_authid() {
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(
+            user.amxx_authid(),
+            Some(user.account_number() * 2 + user.authentication_server())
+        );
+        assert_eq!(SteamId::from(103582791464489035).amxx_authid(), None);
+    }
+
+    #[test]
+    fn chat_room_group_id() {
+        let clan = SteamId::from(103582791464489035);
+        assert_eq!(clan.chat_room_group_id(), Some(34967627));
+
+        let clan_chat = SteamIdBuilder::from(clan).account_type('c').finish();
+        assert_eq!(clan_chat.chat_room_group_id(), Some(34967627));
+
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(user.chat_room_group_id(), None);
+        let lobby = SteamIdBuilder::from(clan).account_type('L').finish();
+        assert_eq!(lobby.chat_room_group_id(), None);
+    }
+
+    #[test]
+    fn try_url() {
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(user.try_url(), Ok(IdFormat::Url(user).to_string()));
+        let clan = SteamId::from(103582791464489035);
+        assert_eq!(clan.try_url(), Ok(IdFormat::Url(clan).to_string()));
+        // Clan chats link to their group.
+        let clan_chat = SteamId::group_chat(34967627, 0);
+        assert_eq!(
+            clan_chat.try_url().unwrap(),
+            "http://steamcommunity.com/gid/[g:1:34967627]"
+        );
+        assert_eq!(SteamId::group_chat(34967627, 3).try_url(), clan.try_url());
+
+        let server = SteamId::from(85568392923371047);
+        assert_eq!(
+            server.try_url(),
+            Err(ParseError::Invalid(Field::AccountType))
+        );
+        let pending = SteamIdBuilder::from(user).account_type('P').finish();
+        assert_eq!(
+            pending.try_url(),
+            Err(ParseError::Invalid(Field::AccountType))
+        );
+    }
+
+    #[test]
+    fn openid_claimed_id() {
+        assert_eq!(
+            SteamId::from_openid_claimed_id(
+                "https://steamcommunity.com/openid/id/76561197990953833"
+            ),
+            Ok(SteamId::from(76561197990953833))
+        );
+        assert_eq!(
+            SteamId::from_openid_claimed_id("https://steamcommunity.com/openid/id/STEAM_1:1:1"),
+            Err(ParseError::Invalid(Field::SteamId64))
+        );
+        assert_eq!(
+            SteamId::from_openid_claimed_id("https://evil.example.com/openid/id/76561197990953833"),
+            Err(ParseError::UnknownFormat)
+        );
+    }
+
+    #[test]
+    fn url_with_host() {
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(
+            user.url_with_host("mirror.example.com"),
+            "http://mirror.example.com/profiles/76561197990953833"
+        );
+        let clan = SteamId::from(103582791464489035);
+        assert_eq!(
+            clan.url_with_host("mirror.example.com"),
+            "http://mirror.example.com/gid/[g:1:34967627]"
+        );
+        assert_eq!(
+            clan.url_with_host("steamcommunity.com"),
+            IdFormat::Url(clan).to_string()
+        );
+    }
 
-impl Display for IdFormat {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            IdFormat::SteamId64(v) => write!(f, "{}", v.id),
-            IdFormat::SteamId2(v) => write!(
-                f,
-                "STEAM_{}:{}:{}",
-                u8::from(v.universe()),
-                v.authentication_server(),
-                v.account_number()
-            ),
-            IdFormat::SteamId2Legacy(v) => write!(
-                f,
-                "STEAM_{}:{}:{}",
-                0,
-                v.authentication_server(),
-                v.account_number()
-            ),
-            IdFormat::SteamId3(v) => write!(
-                f,
-                "[{}:{}:{}]",
-                char::from(AccountType::from(*v)),
-                u8::from(v.universe()),
-                v.id & (mask::AUTH_SERVER | mask::ACCOUNT_NUMBER)
-            ),
-            IdFormat::Url(v) => {
-                let (prefix, postfix) = match v.account_type() {
-                    AccountType::Clan => (GROUP_URL, IdFormat::SteamId3(*v).to_string()),
-                    _ => (PROFILE_URL, v.id.to_string()),
-                };
-                write!(f, "{prefix}{postfix}")
-            }
+    #[test]
+    fn steam_protocol_reciprocity() {
+        let user = SteamId::from(76561197990953833);
+        let uri = user.friend_add_uri();
+        assert_eq!(uri, "steam://friends/add/76561197990953833");
+        assert_eq!(SteamId::from_steam_protocol(&uri), Ok(user));
+
+        assert_eq!(
+            SteamId::from_steam_protocol("steam://friends/message/76561197990953833"),
+            Err(ParseError::UnknownFormat)
+        );
+        assert_eq!(
+            SteamId::from_steam_protocol("steam://friends/add/"),
+            Err(ParseError::Invalid(Field::SteamId64))
+        );
+        assert_eq!(
+            SteamId::from_steam_protocol("steam://friends/add/[U:1:30688105]"),
+            Err(ParseError::Invalid(Field::SteamId64))
+        );
+    }
+
+    #[test]
+    fn openid_reciprocity() {
+        let user = SteamId::from(76561197990953833);
+        let url = user.openid_url();
+        assert_eq!(
+            url,
+            "https://steamcommunity.com/openid/id/76561197990953833"
+        );
+        assert_eq!(SteamId::from_openid_url(&url), Ok(user));
+
+        // Prefix has to match exactly.
+        assert_eq!(
+            SteamId::from_openid_url("http://steamcommunity.com/openid/id/76561197990953833"),
+            Err(ParseError::UnknownFormat)
+        );
+        assert_eq!(
+            SteamId::from_openid_url("https://steamcommunity.com/profiles/76561197990953833"),
+            Err(ParseError::UnknownFormat)
+        );
+        assert_eq!(
+            SteamId::from_openid_url("https://steamcommunity.com/openid/id/"),
+            Err(ParseError::Invalid(Field::SteamId64))
+        );
+        assert_eq!(
+            SteamId::from_openid_url("https://steamcommunity.com/openid/id/+7656119799"),
+            Err(ParseError::Invalid(Field::SteamId64))
+        );
+    }
+
+    #[test]
+    fn format_round_trips() {
+        let clan = SteamId::from(103582791464489035);
+        let ids = [
+            SteamId::from(76561197990953833),
+            clan,
+            SteamId::from(90071992547409920),
+            SteamId::from(0),
+            SteamId::from(u64::MAX),
+        ];
+        for id in ids {
+            assert!(id.round_trips(OutputFormat::SteamId64));
         }
+
+        // SteamId2 only carries individual accounts.
+        assert!(ids[0].round_trips(OutputFormat::SteamId2));
+        assert!(ids[0].round_trips(OutputFormat::SteamId3));
+        assert!(!clan.round_trips(OutputFormat::SteamId2));
+        assert!(!clan.round_trips(OutputFormat::SteamId2Legacy));
     }
-}
 
-/////////////////////////////////////////////////////////////////////////////
-// Unit Testing
-/////////////////////////////////////////////////////////////////////////////
-#[cfg(test)]
-mod tests {
-    use std::hash::Hash;
+    #[test]
+    fn display_is_steamid64() {
+        for id in [0, 76561197990953833, 103582791464489035, u64::MAX] {
+            let id = SteamId::from(id);
+            assert_eq!(id.to_string(), IdFormat::SteamId64(id).to_string());
+        }
+    }
 
-    use crate::*;
+    #[test]
+    fn group_chat_reciprocity() {
+        for (group, channel) in [(34967627, 0), (34967627, 7), (1, 0xFFF), (u32::MAX, 42)] {
+            let chat = SteamId::group_chat(group, channel);
+            assert_eq!(chat.account_type(), AccountType::Chat(ChatType::ClanChat));
+            assert_eq!(chat.universe(), Universe::Public);
+            assert_eq!(chat.chat_room_group_id(), Some(group));
+            assert_eq!(chat.chat_channel(), Some(channel));
+        }
+
+        // Channels are limited to 12 bits.
+        assert_eq!(SteamId::group_chat(1, 0x1001).chat_channel(), Some(1));
+        assert_eq!(SteamId::from(76561197990953833).chat_channel(), None);
+    }
 
-    /// Ensures our documentation and everything line up with the actual defaults
     #[test]
-    fn defaults() {
-        let user = SteamIdBuilder::new().finish();
-        assert_eq!(user.id, 76561197960265728, "Incorrect default SteamId.");
+    fn account_id_reciprocity() {
+        let user: SteamId = "[U:1:30688105]".parse().unwrap();
+        assert_eq!(user.account_id(), 30688105);
+        assert_eq!(SteamId::from_account_id(user.account_id()), user);
+
+        for id in [0, 1, 2, 30688105, u32::MAX] {
+            assert_eq!(SteamId::from_account_id(id).account_id(), id);
+        }
     }
 
-    /// Makes sure builder functions are changing internal values correctly.
     #[test]
-    fn builder_authentication_server() {
-        let reference = SteamIdBuilder::new().finish().id;
+    fn steam64_string() {
+        let user: SteamId = "[U:1:30688105]".parse().unwrap();
+        assert_eq!(user.to_steam64_string(), "76561197990953833");
+        assert_eq!(SteamId::from(0).to_steam64_string(), "0");
+    }
 
-        let set_one = SteamIdBuilder::new().authentication_server(1);
-        assert_eq!(set_one.id, reference | mask::AUTH_SERVER);
+    #[test]
+    fn friend_code_reciprocity() {
+        for id in [0, 1, 22202, 30688105, u32::MAX] {
+            let user = SteamId::from_account_id(id);
+            let code = IdFormat::FriendCode(user).to_string();
+            assert_eq!(SteamId::from_friend_code(&code), Ok(user));
+        }
+    }
 
-        let set_high = SteamIdBuilder::new().authentication_server(8);
-        assert_eq!(set_high.id, reference | mask::AUTH_SERVER);
+    #[test]
+    fn builder_combined_accountid() {
+        let clan = SteamIdBuilder::new()
+            .account_type(AccountType::Clan)
+            .combined_accountid(34967627)
+            .finish();
+        assert_eq!(u64::from(clan), 103582791464489035);
+        assert_eq!(clan.account_type(), AccountType::Clan);
+        assert_eq!(clan.account_id(), 34967627);
 
-        let set_zero = SteamIdBuilder::new().authentication_server(0);
-        assert_eq!(set_zero.id, reference & !mask::AUTH_SERVER);
+        // Only the low 32 bits are replaced.
+        let replaced = SteamIdBuilder::from(clan)
+            .combined_accountid(u32::MAX)
+            .finish();
+        assert_eq!(u64::from(replaced) >> 32, u64::from(clan) >> 32);
+        assert_eq!(replaced.account_id(), u32::MAX);
     }
 
-    /// Makes sure builder functions are changing internal values correctly.
     #[test]
-    fn builder_account_number() {
-        let alfred = SteamIdBuilder::new().account_number(1).finish();
-        assert_eq!(alfred.id, 76561197960265730);
+    fn invite_code_reciprocity() {
+        for (id, code) in [
+            (0, "b"),
+            (0xf, "w"),
+            (0x10, "c-b"),
+            (0x1e240, "cv-dgb"),
+            (0x1d4436a, "ctg-gfjp"),
+            (u32::MAX, "wwww-wwww"),
+        ] {
+            let user = SteamId::from_account_id(id);
+            assert_eq!(IdFormat::InviteCode(user).to_string(), code);
+            assert_eq!(SteamId::from_invite_code(code), Ok(user));
+        }
     }
 
-    /// Makes sure builder functions are changing internal values correctly.
     #[test]
-    fn builder_account_type() {
-        // Internally, `account_type` uses `account_type_preserve_bits` so,
-        // I consider that covered by these tests.
+    fn humanize() {
+        let user = SteamId::from(76561197990953833);
+        let text = user.humanize();
+        assert!(text.contains("15344052"));
+        assert!(text.contains("Individual"));
 
-        let invalid = SteamIdBuilder::new().account_type('I').finish();
-        assert_eq!(invalid.id, 72057598332895232);
-        let invalid = SteamIdBuilder::new().account_type(0).finish();
-        assert_eq!(invalid.id, 72057598332895232);
+        let clan = SteamId::from(103582791464489035);
+        assert_eq!(clan.humanize(), "Public Clan #17483813 (None)");
 
-        let user = SteamIdBuilder::new().account_type('U').finish();
-        assert_eq!(user.id, 76561197960265728);
-        let user = SteamIdBuilder::new().account_type(1).finish();
-        assert_eq!(user.id, 76561197960265728);
+        let chat = clan.group_to_chat();
+        assert_eq!(
+            chat.humanize(),
+            "Public Clan Chat #17483813 (None+ClanChat)"
+        );
+    }
 
-        let multiseat = SteamIdBuilder::new().account_type('M').finish();
-        assert_eq!(multiseat.id, 81064793292668928);
-        let multiseat = SteamIdBuilder::new().account_type(2).finish();
-        assert_eq!(multiseat.id, 81064793292668928);
+    #[test]
+    fn chat_place_reciprocity() {
+        let chat = SteamId::group_chat(34967627, 5);
+        for place in [0, 1, 0x800, 0xFFF] {
+            let moved = chat.with_chat_place(place).unwrap();
+            assert_eq!(moved.chat_channel(), Some(place));
+            assert_eq!(moved.account_id(), chat.account_id());
+            assert_eq!(moved.split_chat().1, ChatType::ClanChat);
+            assert_eq!(moved.with_chat_place(5), Ok(chat));
+        }
+        assert_eq!(
+            chat.with_chat_place(0x1000),
+            Err(ParseError::Invalid(Field::Instance))
+        );
+        assert_eq!(
+            chat.with_chat_place(u32::MAX),
+            Err(ParseError::Invalid(Field::Instance))
+        );
+    }
 
-        let game_server = SteamIdBuilder::new().account_type('G').finish();
-        assert_eq!(game_server.id, 85568392920039424);
-        let game_server = SteamIdBuilder::new().account_type(3).finish();
-        assert_eq!(game_server.id, 85568392920039424);
+    #[test]
+    fn i64_reciprocity() {
+        let rc = SteamIdBuilder::new().universe(Universe::RC).finish();
+        let server = SteamIdBuilder::anonymous_game_server().finish();
+        for id in [
+            76561197990953833,
+            103582791464489035,
+            u64::from(rc),
+            u64::from(server),
+        ] {
+            let steamid = SteamId::from(id);
+            let signed = i64::from(steamid);
+            assert_eq!(signed as u64, id);
+            assert_eq!(SteamId::try_from(signed), Ok(steamid));
+        }
+        // Universes above 127 are stored as negatives, and are accepted when
+        // the rest of the bits are well-formed, even though they don't exist.
+        let high = SteamId::from(0x80 << 56 | (76561197990953833 & !mask::UNIVERSE));
+        let signed = i64::from(high);
+        assert!(signed.is_negative());
+        assert_eq!(SteamId::try_from(signed), Ok(high));
+        assert_eq!(high.account_id(), 30688105);
+        assert_eq!(high.universe(), Universe::Unspecified);
+
+        // The same checks apply to both signs.
+        for (id, field) in [
+            (0, Field::AccountType),
+            (1 << 63, Field::AccountType),
+            (i64::MAX as u64, Field::AccountType),
+            (u64::MAX, Field::AccountType),
+            (0x0110_0003_0000_0001, Field::Instance),
+            (0x8010_0003_0000_0001, Field::Instance),
+        ] {
+            let signed = i64::from(SteamId::from(id));
+            assert_eq!(SteamId::try_from(signed), Err(ParseError::Invalid(field)));
+        }
+        assert_eq!(i64::from(SteamId::from(u64::MAX)), -1);
+    }
 
-        let anon_game_server = SteamIdBuilder::new().account_type('A').finish();
-        assert_eq!(anon_game_server.id, 90071992547409920);
-        let anon_game_server = SteamIdBuilder::new().account_type(4).finish();
-        assert_eq!(anon_game_server.id, 90071992547409920);
+    #[test]
+    fn available_formats() {
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(
+            user.available_formats(),
+            vec![
+                OutputFormat::SteamId64,
+                OutputFormat::SteamId2,
+                OutputFormat::SteamId2Legacy,
+                OutputFormat::SteamId3,
+                OutputFormat::Url,
+                OutputFormat::UrlHttps,
+                OutputFormat::FriendCode,
+                OutputFormat::InviteCode,
+            ]
+        );
 
-        let pending = SteamIdBuilder::new().account_type('P').finish();
-        assert_eq!(pending.id, 94575592174780416);
-        let pending = SteamIdBuilder::new().account_type(5).finish();
-        assert_eq!(pending.id, 94575592174780416);
+        let clan = SteamId::from(103582791464489035);
+        assert_eq!(
+            clan.available_formats(),
+            vec![
+                OutputFormat::SteamId64,
+                OutputFormat::SteamId3,
+                OutputFormat::Url,
+                OutputFormat::UrlHttps,
+            ]
+        );
 
-        let content_server = SteamIdBuilder::new().account_type('C').finish();
-        assert_eq!(content_server.id, 99079191802150912);
-        let content_server = SteamIdBuilder::new().account_type(6).finish();
-        assert_eq!(content_server.id, 99079191802150912);
+        let server: SteamId = "[G:1:3331623]".parse().unwrap();
+        assert_eq!(
+            server.available_formats(),
+            vec![OutputFormat::SteamId64, OutputFormat::SteamId3]
+        );
+    }
 
-        let clan = SteamIdBuilder::new().account_type('g').finish();
-        assert_eq!(clan.id, 103582791429521408);
-        let clan = SteamIdBuilder::new().account_type(7).finish();
-        assert_eq!(clan.id, 103582791429521408);
+    #[test]
+    fn steam2_z() {
+        for id in [0, 76561197990953833, 103582791464489035, u64::MAX] {
+            let id = SteamId::from(id);
+            assert_eq!(id.steam2_z(), id.account_number());
+            assert_eq!(
+                id.steam3_z(),
+                id.steam2_z() << 1 | id.authentication_server()
+            );
+        }
+    }
 
-        let chat = SteamIdBuilder::new().account_type('L').finish();
-        assert_eq!(chat.id, 108121575428980736);
+    #[test]
+    fn join_chat_reciprocity() {
+        let chat: SteamId = "[c:1:34967627]".parse().unwrap();
+        let uri = chat.join_chat_uri().unwrap();
+        assert_eq!(uri, format!("steam://friends/joinchat/{}", u64::from(chat)));
+        assert_eq!(SteamId::from_steam_protocol(&uri), Ok(chat));
+
+        assert_eq!(SteamId::from(76561197990953833).join_chat_uri(), None);
+        assert_eq!(SteamId::from(103582791464489035).join_chat_uri(), None);
+        let lobby = SteamIdBuilder::from(chat).account_type('L').finish();
+        assert_eq!(lobby.join_chat_uri(), None);
+    }
 
-        let chat = SteamIdBuilder::new().account_type('T').finish();
-        assert_eq!(chat.id, 108103983242936320);
+    #[test]
+    fn across_universes() {
+        let clan = SteamId::from(103582791464489035);
+        let all = clan.across_universes();
+        assert_eq!(all.len(), 6);
+        for (v, id) in all.iter().enumerate() {
+            assert_eq!(u8::from(id.universe()), v as u8);
+            assert_eq!(
+                u64::from(*id) & !mask::UNIVERSE,
+                u64::from(clan) & !mask::UNIVERSE
+            );
+        }
+    }
 
-        let chat = SteamIdBuilder::new().account_type('c').finish();
-        assert_eq!(chat.id, 108156759801069568);
+    #[test]
+    fn type_letter_case() {
+        for (steam3, lowercase) in [
+            ("[g:1:34967627]", true),
+            ("[c:1:34967627]", true),
+            ("[a:1:1]", true),
+            ("[U:1:30688105]", false),
+            ("[G:1:3331623]", false),
+            ("[L:1:1]", false),
+            ("[T:1:1]", false),
+        ] {
+            let id: SteamId = steam3.parse().unwrap();
+            assert_eq!(id.type_letter_is_lowercase(), lowercase, "{steam3}");
+        }
+    }
 
-        // Console User aka "P2P SuperSeeder" does not have a char.
-        let console = SteamIdBuilder::new().account_type(9).finish();
-        assert_eq!(console.id, 112589990684262400);
+    #[test]
+    fn detect_format() {
+        for (s, format) in [
+            ("76561197990953833", SteamFormat::SteamId64),
+            ("STEAM_1:1:15344052", SteamFormat::SteamId2),
+            ("STEAM_0:1:15344052", SteamFormat::SteamId2Legacy),
+            ("[U:1:30688105]", SteamFormat::SteamId3),
+            (
+                " https://steamcommunity.com/profiles/76561197990953833 ",
+                SteamFormat::Url,
+            ),
+        ] {
+            assert_eq!(crate::detect_format(s), Some(format), "{s}");
+            assert!(s.parse::<SteamId>().is_ok());
+        }
+        for s in ["", "  ", "gabe", "STEAM", "hello", "U:1:30688105"] {
+            assert_eq!(crate::detect_format(s), None, "{s}");
+        }
+    }
 
-        let anon = SteamIdBuilder::new().account_type('a').finish();
-        assert_eq!(anon.id, 117093590311632896);
-        let anon = SteamIdBuilder::new().account_type(10).finish();
-        assert_eq!(anon.id, 117093590311632896);
+    #[test]
+    fn is_valid() {
+        assert!(SteamId::from(76561197990953833).is_valid());
+        assert!(SteamId::from(103582791464489035).is_valid());
+        assert!(SteamIdBuilder::anonymous_game_server().finish().is_valid());
+
+        assert!(!SteamId::from(0).is_valid());
+        assert!(!"[I:1:30688105]".parse::<SteamId>().unwrap().is_valid());
+        assert!(!"[U:1:0]".parse::<SteamId>().unwrap().is_valid());
+        assert!(!"[U:0:30688105]".parse::<SteamId>().unwrap().is_valid());
+        assert!(!"[U:9:30688105]".parse::<SteamId>().unwrap().is_valid());
     }
 
-    /// account type has side effect we need to verify are following spec.
     #[test]
-    fn builder_account_type_side_effects() {
-        let builder = SteamIdBuilder::new();
+    fn same_account() {
+        let user = SteamId::from(76561197990953833);
+        for builder in [
+            SteamIdBuilder::from(user).desktop(),
+            SteamIdBuilder::from(user).console(),
+            SteamIdBuilder::from(user).web(),
+            SteamIdBuilder::from(user).none_instance(),
+            SteamIdBuilder::from(user).instance(Instance::Raw(3)),
+        ] {
+            assert!(user.same_account(&builder.finish()));
+        }
 
-        // Baselines, default instance is 1.
+        let other = SteamIdBuilder::from(user).account_number(1).finish();
+        assert!(!user.same_account(&other));
+        let beta = SteamIdBuilder::from(user).universe(Universe::Beta).finish();
+        assert!(!user.same_account(&beta));
+        let clan = SteamIdBuilder::from(user)
+            .account_type_preserve_bits(AccountType::Clan)
+            .finish();
+        assert!(!user.same_account(&clan));
+    }
+
+    #[test]
+    fn try_finish_ranges() {
+        let builder = SteamIdBuilder::new().account_number(30688105 >> 1);
+        assert_eq!(builder.try_finish(), Ok(builder.finish()));
+
+        let over = builder.account_number(1 << 31);
         assert_eq!(
-            u32::from(builder.clone().finish().instance()),
-            1,
-            "Baseline instance incorrect."
+            over.try_finish(),
+            Err(BuildError::OutOfRange(Field::AccountNumber))
+        );
+        // Only validate reports an empty individual account.
+        assert_eq!(
+            SteamIdBuilder::new().try_finish(),
+            Ok(SteamIdBuilder::new().finish())
+        );
+        // finish stays forgiving.
+        assert_eq!(over.finish().account_number(), 0);
+        // Setting a value that fits clears the error, whichever setter does it.
+        assert!(over.account_number(1).try_finish().is_ok());
+        assert!(over.combined_accountid(5).try_finish().is_ok());
+        let valid = SteamId::from(76561197990953833);
+        assert_eq!(
+            over.copy_field(Field::SteamId64, &valid).try_finish(),
+            Ok(valid)
         );
         assert_eq!(
-            u32::from(builder.clone().account_type('I').finish().instance()),
-            1
+            over.copy_field(Field::Universe, &valid).try_finish(),
+            Err(BuildError::OutOfRange(Field::AccountNumber))
         );
 
-        // Test side-effecting
         assert_eq!(
-            u32::from(builder.clone().account_type('c').finish().instance()),
-            16384,
-            "Chat account_type not setting instance bits."
+            builder.authentication_server(2).try_finish(),
+            Err(BuildError::OutOfRange(Field::AuthServer))
         );
         assert_eq!(
-            u32::from(builder.clone().account_type('c').finish().instance()),
-            16384,
-            "Chat account_type not resetting instance bits."
+            builder.account_type('I').try_finish(),
+            Err(BuildError::Invalid(
+                Field::AccountType,
+                "account type is invalid"
+            ))
         );
         assert_eq!(
-            u32::from(
-                builder
-                    .clone()
-                    .instance(4)
-                    .account_type('c')
-                    .finish()
-                    .instance()
-            ),
-            16384,
-            "Chat account_type not resetting instance bits."
+            builder
+                .instance(Instance::Desktop(ChatType::ClanChat))
+                .try_finish(),
+            Err(BuildError::Invalid(
+                Field::Instance,
+                "chat type set on a non-chat account"
+            ))
         );
+    }
+
+    #[test]
+    fn sort_key_padding() {
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(user.sort_key(), "00076561197990953833");
+        assert_eq!(SteamId::from(u64::MAX).sort_key(), u64::MAX.to_string());
+
+        let clan = SteamId::from(103582791464489035);
+        let mut keys = [
+            clan.sort_key(),
+            SteamId::from(9).sort_key(),
+            user.sort_key(),
+        ];
+        keys.sort();
         assert_eq!(
-            u32::from(
-                builder
-                    .clone()
-                    .instance(4)
-                    .account_type('A')
-                    .finish()
-                    .instance()
-            ),
-            0,
-            "Chat account_type not resetting instance bits."
+            keys,
+            [
+                SteamId::from(9).sort_key(),
+                user.sort_key(),
+                clan.sort_key()
+            ]
         );
     }
 
-    /// Makes sure builder functions are changing internal values correctly.
     #[test]
-    fn builder_instance() {
-        let alfred = SteamIdBuilder::new().account_number(1).instance(1).finish();
-        assert_eq!(alfred.id, 76561197960265730);
+    fn builder_chat_type() {
+        let side_effect = SteamIdBuilder::new().account_type('c').finish();
+        let direct = SteamIdBuilder::new()
+            .account_type_preserve_bits('c')
+            .none_instance()
+            .chat_type(ChatType::ClanChat)
+            .finish();
+        assert_eq!(direct, side_effect);
+        assert_eq!(
+            direct.id & mask::CHAT_TYPE,
+            side_effect.id & mask::CHAT_TYPE
+        );
 
-        let alfred = SteamIdBuilder::new().account_number(1).instance(2).finish();
-        assert_eq!(alfred.id, 76561202255233026);
+        // The place is left alone.
+        let web = SteamIdBuilder::new()
+            .web()
+            .chat_type(ChatType::MatchMakingLobby)
+            .chat_type(ChatType::ClanChat)
+            .finish();
+        assert_eq!(web.instance(), Instance::Web(ChatType::ClanChat));
+        assert_eq!(web.account_type(), AccountType::Individual);
+    }
 
-        let alfred = SteamIdBuilder::new().account_number(1).instance(4).finish();
-        assert_eq!(alfred.id, 76561210845167618);
+    #[test]
+    fn chat_instance_parts_round_trip() {
+        let chat: SteamId = "[c:1:34967627]".parse().unwrap();
+        for (chat_type, place) in [
+            (ChatType::ClanChat, 0),
+            (ChatType::Lobby, 1),
+            (ChatType::MatchMakingLobby, 0xFFF),
+            (ChatType::None, 4),
+        ] {
+            let built = SteamIdBuilder::from(chat)
+                .chat_instance_parts(chat_type, place)
+                .finish();
+            assert_eq!(built.chat_instance_parts(), (chat_type, place));
+            assert_eq!(built.account_id(), chat.account_id());
+            assert_eq!(built.account_type(), AccountType::Chat(chat_type));
+        }
 
-        let alfred = SteamIdBuilder::new()
-            .account_number(1)
-            .instance(Instance::Web(ChatType::default()))
+        let (chat_type, place) = chat.with_chat_place(7).unwrap().chat_instance_parts();
+        let rebuilt = SteamIdBuilder::from(chat)
+            .chat_instance_parts(chat_type, place)
             .finish();
-        assert_eq!(alfred.id, 76561210845167618);
+        assert_eq!(rebuilt, chat.with_chat_place(7).unwrap());
+        // Places wider than 12 bits are truncated.
+        assert_eq!(
+            SteamIdBuilder::from(chat)
+                .chat_instance_parts(ChatType::ClanChat, 0x1001)
+                .finish()
+                .chat_instance_parts(),
+            (ChatType::ClanChat, 1)
+        );
     }
 
-    /// Makes sure builder functions are changing internal values correctly.
     #[test]
-    fn builder_universe() {
-        let builder = SteamIdBuilder::new().account_number(1);
-        assert_eq!(builder.clone().universe(0).finish().id, 4503603922337794);
-        assert_eq!(builder.clone().universe(1).finish().id, 76561197960265730);
+    fn filenames() {
+        let user = SteamId::from(76561197990953833);
+        assert_eq!(user.to_filename(), "76561197990953833");
         assert_eq!(
-            builder.clone().universe(Universe::Unspecified).finish().id,
-            4503603922337794
+            user.to_filename_fmt(OutputFormat::SteamId3),
+            "_U_1_30688105_"
         );
         assert_eq!(
-            builder.clone().universe(Universe::Public).finish().id,
-            76561197960265730
+            user.to_filename_fmt(OutputFormat::SteamId64),
+            user.to_filename()
         );
+
+        let server = SteamIdBuilder::anonymous_game_server().finish();
+        assert_eq!(server.to_filename_fmt(OutputFormat::SteamId3), "_A_1_0_0_");
+        assert!(!user
+            .to_filename_fmt(OutputFormat::Url)
+            .contains(['/', ':', '?']));
     }
 
-    /// Ensures going from Builder to Id directly and back doesn't change anything.
     #[test]
-    fn from_reciprocity() {
-        let alfred = SteamId::from(4503603922337794);
-        assert_eq!(SteamIdBuilder::from(alfred.clone()).id, 4503603922337794);
-        assert_eq!(SteamIdBuilder::from(alfred).id, 4503603922337794);
+    fn const_accessors() {
+        const USER: SteamId = SteamId::new(76561197990953833);
+        const ACCOUNT_NUMBER: u32 = USER.account_number();
+        const ACCOUNT_ID: u32 = USER.account_id();
+        const AUTH_SERVER: u32 = USER.authentication_server();
+        const RAW: [u32; 3] = [
+            USER.universe_raw() as u32,
+            USER.account_type_raw() as u32,
+            USER.instance_raw(),
+        ];
+        assert_eq!(ACCOUNT_NUMBER, 15344052);
+        assert_eq!(ACCOUNT_ID, 30688105);
+        assert_eq!(AUTH_SERVER, 1);
+        assert_eq!(RAW, [1, 1, 1]);
+        assert_eq!(USER, SteamId::from(76561197990953833));
+
+        let chat = SteamId::from(108156759836037195);
+        assert_eq!(chat.instance_raw(), u32::from(chat.instance()));
+        assert_eq!(chat.account_type_raw(), u8::from(chat.account_type()));
     }
 
     #[test]
-    fn equality() {
-        let alfred = SteamIdBuilder::new().account_number(1).instance(1).finish();
-        let mut hasher = std::collections::hash_map::DefaultHasher::new();
-        assert!(alfred == SteamId::from(76561197960265730));
-        assert!(alfred != SteamId::from(0));
-        let hash1 = alfred.hash(&mut hasher);
-        let hash2 = alfred.hash(&mut hasher);
-        assert!(hash1 == hash2);
+    fn ordered_by_raw_id() {
+        use std::collections::BTreeSet;
+
+        let user = SteamId::from(76561197990953833);
+        let clan = SteamId::from(103582791464489035);
+        // A higher account number, but a lower raw id.
+        let unspecified = SteamIdBuilder::new()
+            .universe(Universe::Unspecified)
+            .account_number(u32::MAX as u64 >> 1)
+            .finish();
+        let set: BTreeSet<SteamId> = [clan, user, unspecified, user].into_iter().collect();
+        assert_eq!(
+            set.into_iter().collect::<Vec<_>>(),
+            [unspecified, user, clan]
+        );
+        assert!(unspecified.account_number() > user.account_number());
+    }
+
+    #[test]
+    fn handle_round_trip() {
+        let user = SteamId::from(76561197990953833);
+        let clan = SteamId::from(103582791464489035);
+        assert_eq!(user.handle(), "U15344052");
+        assert_eq!(clan.handle(), "g17483813");
+        for id in [user, clan] {
+            let parsed = SteamId::from_handle(&id.handle()).unwrap();
+            assert_eq!(parsed.handle(), id.handle());
+            assert_eq!(parsed.account_type(), id.account_type());
+            assert_eq!(parsed.account_number(), id.account_number());
+            assert_eq!(parsed.authentication_server(), 0);
+            assert_eq!(
+                SteamId::from_handle(&format!("@{}", id.handle())),
+                Ok(parsed)
+            );
+        }
+        // Without an authentication server bit nothing is lost.
+        let even = SteamId::from(76561197990953832);
+        assert_eq!(SteamId::from_handle(&even.handle()), Ok(even));
+
+        assert_eq!(SteamId::from_handle("@"), Err(ParseError::Empty));
+        assert_eq!(SteamId::from_handle("U"), Err(ParseError::TooShort));
+        assert_eq!(
+            SteamId::from_handle("X1"),
+            Err(ParseError::Invalid(Field::AccountType))
+        );
+        assert_eq!(
+            SteamId::from_handle("U+1"),
+            Err(ParseError::Invalid(Field::AccountNumber))
+        );
+        assert_eq!(
+            SteamId::from_handle("U2147483648"),
+            Err(ParseError::Invalid(Field::AccountNumber))
+        );
+        assert!(SteamId::from_handle("U2147483647").is_ok());
+    }
+
+    #[test]
+    fn group_chat_conversion() {
+        let group = SteamId::from(103582791464489035);
+        let chat = SteamId::from(108156759836037195);
+        assert_eq!(group.group_to_chat(), chat);
+        assert_eq!(chat.chat_to_group(), group);
+        assert_eq!(chat.instance(), Instance::None(ChatType::ClanChat));
+        assert_eq!(group.instance(), Instance::None(ChatType::None));
+        assert_eq!(chat.account_id(), group.account_id());
+
+        // Channels are dropped going back to the group.
+        let channel = SteamId::group_chat(group.account_id(), 3);
+        assert_eq!(channel.chat_to_group(), group);
+    }
+
+    #[test]
+    fn friend_code_matching() {
+        let gaben = SteamId::from(76561197960287930);
+        assert!(gaben.matches_friend_code("SUCVS-FADA"));
+        // Other fields aren't part of the code.
+        let web = SteamIdBuilder::from(gaben).web().finish();
+        assert!(web.matches_friend_code("SUCVS-FADA"));
+
+        let user = SteamId::from(76561197990953833);
+        assert!(!user.matches_friend_code("SUCVS-FADA"));
+        assert!(!gaben.matches_friend_code("SUCVS"));
+        assert!(!gaben.matches_friend_code(""));
+    }
+
+    #[test]
+    fn table_getters() {
+        let user = SteamId::from(76561197990953833);
+        let clan = SteamId::from(103582791464489035);
+        assert_eq!(user.account_type_char(), 'U');
+        assert_eq!(clan.account_type_char(), 'g');
+
+        assert_eq!(user.universe_name(), "public");
+        for (universe, name) in [(Universe::Beta, "beta"), (Universe::RC, "rc")] {
+            let id = SteamIdBuilder::from(user).universe(universe).finish();
+            assert_eq!(id.universe_name(), name);
+        }
+    }
+
+    #[test]
+    fn explain_lines() {
+        let user = SteamId::from(76561197990953833);
+        let explained = user.explain();
+        for format in [
+            IdFormat::SteamId64,
+            IdFormat::SteamId2,
+            IdFormat::SteamId2Legacy,
+            IdFormat::SteamId3,
+            IdFormat::Url,
+        ] {
+            let formatted = format(user).to_string();
+            assert!(explained.contains(&formatted), "missing {formatted}");
+        }
+        assert!(explained.contains("Account number:   15344052"));
+        assert_eq!(explained.lines().count(), 11);
+        assert!(!explained.ends_with('\n'));
     }
 }
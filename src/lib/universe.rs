@@ -1,5 +1,7 @@
-use std::fmt::Display;
+use core::fmt::Display;
+use core::str::FromStr;
 
+use crate::errors::{Field, ParseError};
 use crate::{mask, shift, SteamId};
 
 /// Each universe is a self-contained Steam instance.
@@ -25,9 +27,89 @@ pub enum Universe {
     RC,
 }
 
+impl Universe {
+    /// Converts a raw value into a Universe, failing on values with no
+    /// matching variant rather than returning [Universe::Unspecified] like
+    /// [From] does.
+    ///
+    /// **Note**: `TryFrom<u8>` can't be used for this, as the lossy `From<u8>`
+    /// already provides an infallible one.
+    /// # Example
+    /// ```
+    /// use steamid::Universe;
+    ///
+    /// assert_eq!(Universe::try_from_raw(1), Ok(Universe::Public));
+    /// assert!(Universe::try_from_raw(100).is_err());
+    /// ```
+    pub fn try_from_raw(v: u8) -> Result<Universe, ParseError> {
+        let universe = Universe::from(v);
+        (u8::from(universe) == v)
+            .then_some(universe)
+            .ok_or(ParseError::Invalid(Field::Universe))
+    }
+
+    /// Returns the lowercase name of this universe, as used by the Steam Web API.
+    /// # Example
+    /// ```
+    /// use steamid::Universe;
+    ///
+    /// assert_eq!(Universe::Public.name(), "public");
+    /// ```
+    #[rustfmt::skip]
+    pub fn name(&self) -> &'static str {
+        use Universe::*;
+        match self {
+            Unspecified => "unspecified",
+            Public      => "public",
+            Beta        => "beta",
+            Internal    => "internal",
+            Dev         => "dev",
+            RC          => "rc",
+        }
+    }
+
+    #[rustfmt::skip]
+    fn display_name(&self) -> &'static str {
+        use Universe::*;
+        match self {
+            Unspecified => "Unspecified",
+            Public      => "Public",
+            Beta        => "Beta",
+            Internal    => "Internal",
+            Dev         => "Development",
+            RC          => "Release Candidate",
+        }
+    }
+}
+
+/// Human readable names, kept stable regardless of the [Debug] output.
 impl Display for Universe {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.display_name())
+    }
+}
+
+/// Parses either the numeric value, or a name from [Universe::name] or
+/// [Display], ignoring case.
+/// # Example
+/// ```
+/// use steamid::Universe;
+///
+/// assert_eq!("1".parse(), Ok(Universe::Public));
+/// assert_eq!("public".parse(), Ok(Universe::Public));
+/// assert_eq!("Release Candidate".parse(), Ok(Universe::RC));
+/// ```
+impl FromStr for Universe {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(v) = s.parse::<u8>() {
+            return Universe::try_from_raw(v);
+        }
+        (0..=u8::MAX)
+            .map_while(|v| Universe::try_from_raw(v).ok())
+            .find(|u| s.eq_ignore_ascii_case(u.name()) || s.eq_ignore_ascii_case(u.display_name()))
+            .ok_or(ParseError::Invalid(Field::Universe))
     }
 }
 
@@ -62,15 +144,21 @@ impl From<u8> for Universe {
     }
 }
 
-impl From<SteamId> for Universe {
+impl From<&SteamId> for Universe {
     #[rustfmt::skip]
-    fn from(steamid: SteamId) -> Self {
+    fn from(steamid: &SteamId) -> Self {
         let universe_val = (steamid.id & mask::UNIVERSE) >> shift::UNIVERSE;
         // We can safely cast as u8 since Universe is 8-bits in packed repr.
         Universe::from(universe_val as u8)
     }
 }
 
+impl From<SteamId> for Universe {
+    fn from(steamid: SteamId) -> Self {
+        Universe::from(&steamid)
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////
 // Unit Testing
 /////////////////////////////////////////////////////////////////////////////
@@ -87,12 +175,83 @@ mod tests {
     }
 
     #[test]
-    fn universe_fmt_debug() {
-        for v in 1..=6 {
-            let uv = Universe::from(v);
-            let fmt = uv.to_string();
-            let dbg = format!("{:?}", uv);
-            assert!(fmt == dbg);
+    fn checked_conversion() {
+        for v in 0..=5 {
+            assert_eq!(Universe::try_from_raw(v), Ok(Universe::from(v)));
         }
+        assert_eq!(
+            Universe::try_from_raw(6),
+            Err(errors::ParseError::Invalid(errors::Field::Universe))
+        );
+        assert!(Universe::try_from_raw(255).is_err());
+    }
+
+    #[test]
+    fn universe_name() {
+        assert_eq!(Universe::Unspecified.name(), "unspecified");
+        assert_eq!(Universe::Public.name(), "public");
+        assert_eq!(Universe::Beta.name(), "beta");
+        assert_eq!(Universe::Internal.name(), "internal");
+        assert_eq!(Universe::Dev.name(), "dev");
+        assert_eq!(Universe::RC.name(), "rc");
+    }
+
+    #[test]
+    fn universe_fmt_display() {
+        assert_eq!(Universe::Unspecified.to_string(), "Unspecified");
+        assert_eq!(Universe::Public.to_string(), "Public");
+        assert_eq!(Universe::Beta.to_string(), "Beta");
+        assert_eq!(Universe::Internal.to_string(), "Internal");
+        assert_eq!(Universe::Dev.to_string(), "Development");
+        assert_eq!(Universe::RC.to_string(), "Release Candidate");
+        // Debug is left alone for diagnostics.
+        assert_eq!(format!("{:?}", Universe::RC), "RC");
+    }
+
+    #[test]
+    fn from_str() {
+        use errors::{Field, ParseError};
+
+        for v in 0..=5 {
+            let universe = Universe::from(v);
+            assert_eq!(v.to_string().parse(), Ok(universe));
+            assert_eq!(universe.name().parse(), Ok(universe));
+            assert_eq!(universe.to_string().parse(), Ok(universe));
+            assert_eq!(universe.name().to_uppercase().parse(), Ok(universe));
+        }
+        assert_eq!("Public".parse(), Ok(Universe::Public));
+        assert_eq!("DEV".parse(), Ok(Universe::Dev));
+
+        for s in ["", "6", "256", "-1", "Steam", " public"] {
+            assert_eq!(
+                s.parse::<Universe>(),
+                Err(ParseError::Invalid(Field::Universe)),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn from_steamid_ref() {
+        fn fields(id: &SteamId) -> (Universe, AccountType, Instance, ChatType) {
+            (
+                Universe::from(id),
+                AccountType::from(id),
+                Instance::from(id),
+                ChatType::from(id),
+            )
+        }
+
+        let chat: SteamId = "[c:1:34967627]".parse().unwrap();
+        assert_eq!(
+            fields(&chat),
+            (
+                Universe::from(chat),
+                AccountType::from(chat),
+                Instance::from(chat),
+                ChatType::from(chat),
+            )
+        );
+        assert_eq!(Universe::from(&chat), Universe::Public);
     }
 }
@@ -0,0 +1,63 @@
+//! SteamIds of special accounts that are useful to recognize.
+//!
+//! See [SteamId::is_well_known] to check against all of them at once.
+use crate::SteamId;
+
+/// Gabe Newell's personal account, `[U:1:22202]`.
+pub const GABE_NEWELL: SteamId = SteamId {
+    id: 76561197960287930,
+};
+
+/// Blank logon used by anonymous users, `[a:1:0]`.
+pub const ANONYMOUS_USER: SteamId = SteamId {
+    id: 117093590311632896,
+};
+
+/// Blank logon used by anonymous game servers, `[A:1:0:0]`.
+pub const ANONYMOUS_GAME_SERVER: SteamId = SteamId {
+    id: 90071992547409920,
+};
+
+/// Base console user (P2P SuperSeeder) account, with no account number.
+pub const CONSOLE_USER: SteamId = SteamId {
+    id: 112589990684262400,
+};
+
+/// Every SteamId in this module.
+pub const ALL: &[SteamId] = &[
+    GABE_NEWELL,
+    ANONYMOUS_USER,
+    ANONYMOUS_GAME_SERVER,
+    CONSOLE_USER,
+];
+
+/////////////////////////////////////////////////////////////////////////////
+// Unit Testing
+/////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use crate::well_known::*;
+    use crate::*;
+
+    /// Ensures the constants line up with what the builder produces.
+    #[test]
+    fn values() {
+        assert_eq!(IdFormat::SteamId3(GABE_NEWELL).to_string(), "[U:1:22202]");
+        assert_eq!(
+            ANONYMOUS_USER,
+            SteamIdBuilder::new()
+                .account_type(AccountType::AnonUser)
+                .finish()
+        );
+        assert_eq!(
+            ANONYMOUS_GAME_SERVER,
+            SteamIdBuilder::anonymous_game_server().finish()
+        );
+        assert_eq!(
+            CONSOLE_USER,
+            SteamIdBuilder::new()
+                .account_type(AccountType::ConsoleUser)
+                .finish()
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use std::process::Command;
+
+#[test]
+fn json_lines() {
+    let output = Command::new(env!("CARGO_BIN_EXE_converter"))
+        .args(["--json", "[U:1:30688105]", "not \"an\" id"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("converter output isn't JSON"))
+        .collect();
+    assert_eq!(lines.len(), 2);
+
+    assert_eq!(lines[0]["input"], "[U:1:30688105]");
+    assert_eq!(lines[0]["steamid64"], "76561197990953833");
+    assert_eq!(lines[0]["steamid"], "STEAM_1:1:15344052");
+    assert_eq!(lines[0]["steamid3"], "[U:1:30688105]");
+    assert!(lines[0].get("error").is_none());
+
+    assert_eq!(lines[1]["input"], "not \"an\" id");
+    assert_eq!(lines[1]["error"], "unable to identify SteamId format");
+}
+
+#[test]
+fn explain() {
+    let output = Command::new(env!("CARGO_BIN_EXE_converter"))
+        .args(["--explain", "[U:1:30688105]"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    for expected in [
+        "76561197990953833",
+        "STEAM_1:1:15344052",
+        "STEAM_0:1:15344052",
+        "[U:1:30688105]",
+        "http://steamcommunity.com/profiles/76561197990953833",
+        "15344052",
+    ] {
+        assert!(stdout.contains(expected), "missing {expected}");
+    }
+}
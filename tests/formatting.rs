@@ -1,5 +1,5 @@
 use steamid::IdFormat::*;
-use steamid::SteamId;
+use steamid::{SteamId, SteamIdBuilder};
 
 #[test]
 fn id_to_string() {
@@ -33,3 +33,54 @@ fn id_to_string() {
         "http://steamcommunity.com/profiles/76561197990953833"
     );
 }
+
+#[test]
+fn game_server_instance() {
+    use std::str::FromStr;
+
+    for steam3 in [
+        "[G:1:3331623:1]",
+        "[A:1:3331623:1]",
+        "[A:1:3331623:0]",
+        "[G:1:3331623]",
+    ] {
+        let id = SteamId::from_str(steam3).unwrap();
+        assert_eq!(SteamId3(id).to_string(), steam3);
+    }
+    let server = SteamId::from_str("[G:1:3331623:1]").unwrap();
+    assert_eq!(u64::from(server), 85568397218338343);
+
+    // Instance-less anonymous game servers render with their instance.
+    let anon = SteamId::from_str("[A:1:3331623]").unwrap();
+    assert_eq!(SteamId3(anon).to_string(), "[A:1:3331623:0]");
+}
+
+#[test]
+fn friend_code() {
+    let gaben = SteamId::from(76561197960287930);
+    assert_eq!(FriendCode(gaben).to_string(), "SUCVS-FADA");
+
+    // Only the account id is encoded.
+    let clan = SteamIdBuilder::from(gaben).account_type('g').finish();
+    assert_eq!(FriendCode(clan).to_string(), "SUCVS-FADA");
+}
+
+#[test]
+fn url_https() {
+    let user = SteamId::from(76561197990953833);
+    let clan = SteamId::from(103582791464489035);
+    assert_eq!(
+        UrlHttps(user).to_string(),
+        "https://steamcommunity.com/profiles/76561197990953833"
+    );
+    assert_eq!(
+        UrlHttps(clan).to_string(),
+        "https://steamcommunity.com/gid/[g:1:34967627]"
+    );
+    for id in [user, clan] {
+        let http = Url(id).to_string();
+        let https = UrlHttps(id).to_string();
+        assert_eq!(http.strip_prefix("http://"), https.strip_prefix("https://"));
+        assert_eq!(https.parse(), Ok(id));
+    }
+}
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use steamid::errors::{Field, ParseError};
 use steamid::{AccountType, Instance, SteamId, Universe};
 
 #[test]
@@ -211,3 +212,338 @@ fn from_steamid3_str() {
         "Not properly masking auth server bit"
     );
 }
+
+#[test]
+fn from_steamid3_lenient_str() {
+    // Auth server and account number split into separate fields.
+    let mitch = SteamId::from_str_lenient("[U:1:1:485059260]").unwrap();
+    assert_eq!(mitch, SteamId::from_str("[U:1:970118521]").unwrap());
+    assert_eq!(mitch.authentication_server(), 1);
+    assert_eq!(mitch.account_number(), 485059260);
+
+    let zero = SteamId::from_str_lenient("[U:1:0:485059260]").unwrap();
+    assert_eq!(zero, SteamId::from_str("[U:1:970118520]").unwrap());
+
+    // Standard formats are still accepted.
+    assert_eq!(SteamId::from_str_lenient("[U:1:970118521]").unwrap(), mitch);
+    assert_eq!(
+        SteamId::from_str_lenient("STEAM_1:1:485059260").unwrap(),
+        mitch
+    );
+
+    // The fourth field is never read as an instance, so the third must be
+    // a valid auth server bit.
+    assert_eq!(
+        SteamId::from_str_lenient("[U:1:30688105:1]"),
+        Err(ParseError::Invalid(Field::AuthServer))
+    );
+    assert_eq!(
+        SteamId::from_str_lenient("[U:1:1:2147483648]"),
+        Err(ParseError::Invalid(Field::AccountNumber))
+    );
+    assert_eq!(
+        SteamId::from_str_lenient("[U:1:1:2:3]"),
+        Err(ParseError::UnknownFormat)
+    );
+
+    // Strict parsing still rejects the split form.
+    assert_eq!(
+        SteamId::from_str("[U:1:1:485059260]"),
+        Err(ParseError::UnknownFormat)
+    );
+}
+
+#[test]
+fn from_str_strict() {
+    // Everything valid still parses the same.
+    for valid in [
+        "76561197990953833",
+        "STEAM_1:1:15344052",
+        "[U:1:30688105]",
+        "[g:1:34967627]",
+    ] {
+        assert_eq!(SteamId::parse_strict(valid), SteamId::from_str(valid));
+    }
+
+    // Unknown universes.
+    assert!(SteamId::from_str("STEAM_9:1:1").is_ok());
+    assert_eq!(
+        SteamId::parse_strict("STEAM_9:1:1"),
+        Err(ParseError::Invalid(Field::Universe))
+    );
+    assert!(SteamId::from_str("[U:9:3]").is_ok());
+    assert_eq!(
+        SteamId::parse_strict("[U:9:3]"),
+        Err(ParseError::Invalid(Field::Universe))
+    );
+
+    // No coercion of the legacy universe.
+    assert_eq!(
+        SteamId::from_str("STEAM_0:1:1").unwrap().universe(),
+        Universe::Public
+    );
+    assert_eq!(
+        SteamId::parse_strict("STEAM_0:1:1").unwrap().universe(),
+        Universe::Unspecified
+    );
+
+    // Unknown values packed in a SteamId64.
+    let unknown_universe = (9u64 << 56 | 76561197990953833 & !(0xFF << 56)).to_string();
+    assert!(SteamId::from_str(&unknown_universe).is_ok());
+    assert_eq!(
+        SteamId::parse_strict(&unknown_universe),
+        Err(ParseError::Invalid(Field::Universe))
+    );
+    let unknown_type = (76561197990953833u64 | 0xF << 52).to_string();
+    assert!(SteamId::from_str(&unknown_type).is_ok());
+    assert_eq!(
+        SteamId::parse_strict(&unknown_type),
+        Err(ParseError::Invalid(Field::AccountType))
+    );
+    let unknown_instance = (76561197990953833u64 | 3 << 32).to_string();
+    assert!(SteamId::from_str(&unknown_instance).is_ok());
+    assert_eq!(
+        SteamId::parse_strict(&unknown_instance),
+        Err(ParseError::Invalid(Field::Instance))
+    );
+}
+
+#[test]
+fn from_steamid3_instance_str() {
+    let server = SteamId::from_str("[G:1:3331623:1]").unwrap();
+    assert_eq!(server.account_type(), AccountType::GameServer);
+    assert_eq!(u32::from(server.instance()), 1);
+    // The instance is never read as a split account number for servers.
+    assert_eq!(SteamId::from_str_lenient("[G:1:3331623:1]"), Ok(server));
+
+    let anon = SteamId::from_str("[A:1:3331623:4]").unwrap();
+    assert_eq!(anon.account_type(), AccountType::AnonGameServer);
+    assert_eq!(u32::from(anon.instance()), 4);
+
+    assert_eq!(
+        SteamId::from_str("[G:1:3331623:1048576]"),
+        Err(ParseError::Invalid(Field::Instance))
+    );
+    assert_eq!(
+        SteamId::from_str("[G:1:3331623:x]"),
+        Err(ParseError::Invalid(Field::Instance))
+    );
+    assert_eq!(
+        SteamId::from_str("[g:1:3331623:1]"),
+        Err(ParseError::UnknownFormat)
+    );
+}
+
+#[test]
+fn empty_or_unknown_str() {
+    assert_eq!(SteamId::from_str(""), Err(ParseError::Empty));
+    // Input is trimmed before dispatching.
+    assert_eq!(SteamId::from_str("   "), Err(ParseError::Empty));
+    assert_eq!(SteamId::from_str("\n\t"), Err(ParseError::Empty));
+    assert_eq!(SteamId::from_str("?"), Err(ParseError::UnknownFormat));
+    assert_eq!(SteamId::from_str("  ?  "), Err(ParseError::UnknownFormat));
+}
+
+#[test]
+fn from_friend_code_str() {
+    let gaben = SteamId::from_friend_code("SUCVS-FADA").unwrap();
+    assert_eq!(u64::from(gaben), 76561197960287930);
+    assert_eq!(gaben.account_type(), AccountType::Individual);
+
+    assert_eq!(SteamId::from_friend_code(""), Err(ParseError::Empty));
+    assert_eq!(
+        SteamId::from_friend_code("SUCVS"),
+        Err(ParseError::TooShort)
+    );
+    for code in [
+        "SUCVS-FADAA",
+        "SUCVSFADA-A",
+        "SUCVS-FAD-",
+        "SUCVS-FADI",
+        "SUCVS-FAD0",
+        "sucvs-fada",
+        "AAAA-SUCVS-FADA",
+    ] {
+        assert_eq!(
+            SteamId::from_friend_code(code),
+            Err(ParseError::UnknownFormat),
+            "{code}"
+        );
+    }
+    // Well formed, but the hash bits don't match the account id.
+    assert_eq!(
+        SteamId::from_friend_code("SUCVS-FADB"),
+        Err(ParseError::Invalid(Field::AccountNumber))
+    );
+}
+
+#[test]
+fn from_url_str() {
+    let user = SteamId::from(76561197990953833);
+    for url in [
+        "http://steamcommunity.com/profiles/76561197990953833",
+        "https://steamcommunity.com/profiles/76561197990953833/",
+        "https://steamcommunity.com/profiles/76561197990953833/games/?tab=all",
+        "https://steamcommunity.com/profiles/76561197990953833?l=english",
+    ] {
+        assert_eq!(SteamId::from_url(url), Ok(user), "{url}");
+    }
+    assert_eq!(
+        SteamId::from_url("https://steamcommunity.com/profiles//games"),
+        Err(ParseError::Invalid(Field::SteamId64))
+    );
+}
+
+#[test]
+fn from_invite_code_str() {
+    let user = SteamId::from_account_id(30688105);
+    for code in [
+        "ctg-gfjn",
+        "ctggfjn",
+        "c-t-g-g-f-j-n",
+        "https://s.team/p/ctg-gfjn",
+        " https://s.team/p/ctg-gfjn ",
+    ] {
+        assert_eq!(SteamId::from_invite_code(code), Ok(user), "{code}");
+    }
+
+    assert_eq!(SteamId::from_invite_code(""), Err(ParseError::Empty));
+    assert_eq!(
+        SteamId::from_invite_code("https://s.team/p/"),
+        Err(ParseError::Empty)
+    );
+    for code in [
+        "ctg-gfja",
+        "CTG-GFJN",
+        "ctg gfjn",
+        "-",
+        "ctg-gfjn-bb",
+        "http://s.team/p/ctg-gfjn",
+    ] {
+        assert_eq!(
+            SteamId::from_invite_code(code),
+            Err(ParseError::Invalid(Field::AccountNumber)),
+            "{code}"
+        );
+    }
+}
+
+#[test]
+fn from_community_url_str() {
+    use steamid::OutputFormat;
+
+    let user = SteamId::from(76561197990953833);
+    for url in [
+        "http://steamcommunity.com/profiles/76561197990953833",
+        "https://steamcommunity.com/profiles/76561197990953833",
+        "https://steamcommunity.com/profiles/76561197990953833/",
+    ] {
+        assert_eq!(SteamId::from_str(url), Ok(user), "{url}");
+    }
+
+    let clan = SteamId::from(103582791464489035);
+    for url in [
+        "http://steamcommunity.com/gid/[g:1:34967627]",
+        "https://steamcommunity.com/gid/[g:1:34967627]/",
+        "https://steamcommunity.com/gid/103582791464489035",
+    ] {
+        assert_eq!(SteamId::from_str(url), Ok(clan), "{url}");
+    }
+    assert!(clan.round_trips(OutputFormat::Url));
+
+    for url in [
+        "https://example.com/profiles/76561197990953833",
+        "https://steamcommunity.com/id/gabelogannewell",
+        "ftp://steamcommunity.com/profiles/76561197990953833",
+        "hello",
+    ] {
+        assert_eq!(
+            SteamId::from_str(url),
+            Err(ParseError::UnknownFormat),
+            "{url}"
+        );
+    }
+}
+
+#[test]
+fn from_quoted_lenient_str() {
+    let user = SteamId::from(76561197990953833);
+    for s in [
+        r#""76561197990953833""#,
+        r#""\"76561197990953833\"""#,
+        r#" " \"76561197990953833\" " "#,
+        r#""[U:1:30688105]""#,
+        r#"\"STEAM_1:1:15344052\""#,
+    ] {
+        assert_eq!(SteamId::from_str_lenient(s), Ok(user), "{s}");
+    }
+    // Only lenient parsing removes quotes.
+    assert_eq!(
+        SteamId::from_str(r#""76561197990953833""#),
+        Err(ParseError::UnknownFormat)
+    );
+    // Unbalanced quotes are left alone.
+    assert!(SteamId::from_str_lenient(r#""76561197990953833"#).is_err());
+    assert_eq!(SteamId::from_str_lenient(r#""""#), Err(ParseError::Empty));
+}
+
+#[test]
+fn parse_many_str() {
+    let user = SteamId::from(76561197990953833);
+    let input = "
+        76561197990953833 STEAM_1:1:15344052
+        [U:1:30688105]\tSTEAM_X:1:1
+        https://steamcommunity.com/profiles/76561197990953833/
+    ";
+    let results: Vec<_> = SteamId::parse_many(input).collect();
+    assert_eq!(
+        results,
+        vec![
+            ("76561197990953833", Ok(user)),
+            ("STEAM_1:1:15344052", Ok(user)),
+            ("[U:1:30688105]", Ok(user)),
+            ("STEAM_X:1:1", Err(ParseError::Invalid(Field::Universe))),
+            (
+                "https://steamcommunity.com/profiles/76561197990953833/",
+                Ok(user)
+            ),
+        ]
+    );
+    assert_eq!(SteamId::parse_many(" \n\t ").count(), 0);
+}
+
+#[test]
+fn parse_list_str() {
+    let user = SteamId::from(76561197990953833);
+    let input = "76561197990953833,STEAM_1:1:15344052, [U:1:30688105]\n\n,,STEAM_X:1:1\r\n";
+    assert_eq!(
+        steamid::parse_list(input),
+        vec![
+            Ok(user),
+            Ok(user),
+            Ok(user),
+            Err(ParseError::Invalid(Field::Universe)),
+        ]
+    );
+    assert!(steamid::parse_list(" , \n").is_empty());
+}
+
+#[test]
+fn from_api_field_str() {
+    assert_eq!(
+        SteamId::from_api_field("76561197990953833"),
+        Ok(SteamId::from(76561197990953833))
+    );
+    for other in ["[U:1:30688105]", "STEAM_1:1:15344052", "+76561197990953833"] {
+        assert_eq!(
+            SteamId::from_api_field(other),
+            Err(ParseError::UnknownFormat),
+            "{other}"
+        );
+    }
+    assert_eq!(SteamId::from_api_field(""), Err(ParseError::Empty));
+    assert_eq!(
+        SteamId::from_api_field("99999999999999999999"),
+        Err(ParseError::Invalid(Field::SteamId64))
+    );
+}
@@ -1,2 +1,3 @@
+pub mod converter;
 pub mod formatting;
 pub mod parsing;